@@ -67,7 +67,7 @@ impl<'a> AppText<'a> {
             _ => 14,
         };
 
-        let final_color = self.color.unwrap_or_else(|| match self.status {
+        let final_color = self.color.unwrap_or(match self.status {
             TextStatus::Normal => palette.text_main,
             TextStatus::Caption => palette.text_subtle,
             TextStatus::Danger => palette.danger,
@@ -115,6 +115,7 @@ pub fn app_sidebar<'a>(current_page: Page) -> Element<'a, Message> {
         nav_item("Home", "", Page::Home, current_page),
         nav_item("Instances", "", Page::Instances, current_page),
         nav_item("Accounts", "", Page::Accounts, current_page),
+        nav_item("Console", "", Page::Console, current_page),
     ]
     .spacing(8);
 
@@ -11,14 +11,14 @@ use std::sync::Arc;
 
 use iced::{
     Element, Task, Theme,
-    widget::{container, row, text},
+    widget::{container, row},
 };
 
 use crate::{
     common::sidebar::{self, app_sidebar},
     module::{
         account::repository::AccountRepository, config::repository::ConfigRepository,
-        mojang::repository::MojangRepository,
+        mojang::repository::MojangRepository, process::repository::ProcessRepository,
     },
     state::*,
 };
@@ -26,7 +26,42 @@ use crate::{
 fn update(state: &mut State, message: Message) -> Task<Message> {
     #[allow(unreachable_patterns)]
     match message {
-        Message::Home(message) => state.saved_screen.home.update(message).map(Message::Home),
+        Message::Home(message) => {
+            // Console follows output of every launched game
+            let console_task = match &message {
+                ui::home::Message::LaunchFinished(id, Ok(())) => state
+                    .saved_screen
+                    .console
+                    .attach(id, id)
+                    .map(Message::Console),
+                _ => Task::none(),
+            };
+
+            Task::batch([
+                state.saved_screen.home.update(message).map(Message::Home),
+                console_task,
+            ])
+        }
+        Message::Instances(message) => {
+            let console_task = match &message {
+                // Instances launch the version created by their loader
+                ui::instances::Message::LaunchFinished(id, Ok(version_id)) => state
+                    .saved_screen
+                    .console
+                    .attach(id, version_id)
+                    .map(Message::Console),
+                _ => Task::none(),
+            };
+
+            Task::batch([
+                state
+                    .saved_screen
+                    .instances
+                    .update(message)
+                    .map(Message::Instances),
+                console_task,
+            ])
+        }
         Message::Settings(message) => {
             match &message {
                 ui::settings::Message::ThemeChanged(new_theme) => {
@@ -56,6 +91,11 @@ fn update(state: &mut State, message: Message) -> Task<Message> {
                 .update(message)
                 .map(Message::Settings)
         }
+        Message::Console(message) => state
+            .saved_screen
+            .console
+            .update(message)
+            .map(Message::Console),
         Message::Accounts(message) => {
             state.saved_screen.accounts.update(message);
             Task::none()
@@ -74,9 +114,10 @@ fn view(state: &State) -> Element<'_, Message> {
 
     let content: Element<'_, Message> = match &state.current_page {
         Page::Home => state.saved_screen.home.view().map(Message::Home),
+        Page::Instances => state.saved_screen.instances.view().map(Message::Instances),
         Page::Settings => state.saved_screen.settings.view().map(Message::Settings),
         Page::Accounts => state.saved_screen.accounts.view().map(Message::Accounts),
-        _ => text("Page not found").into(),
+        Page::Console => state.saved_screen.console.view().map(Message::Console),
     };
 
     row![
@@ -101,19 +142,44 @@ fn new() -> (State, Task<Message>) {
 
     let mojang_repo = Arc::new(MojangRepository::new());
     let account_repo = Arc::new(AccountRepository::new());
+    let process_repo = Arc::new(ProcessRepository::new());
 
-    let (home_screen, home_task) =
-        ui::home::HomeScreen::new(mojang_repo.clone(), account_repo.clone());
+    let (home_screen, home_task) = ui::home::HomeScreen::new(
+        mojang_repo.clone(),
+        account_repo.clone(),
+        process_repo.clone(),
+    );
 
-    let settings_screen = ui::settings::SettingsScreen::new(theme.clone());
+    let (instances_screen, instances_task) = ui::instances::InstancesScreen::new(
+        mojang_repo.clone(),
+        account_repo.clone(),
+        process_repo.clone(),
+    );
+
+    let (settings_screen, settings_task) = ui::settings::SettingsScreen::new(theme.clone());
 
     let accounts_screen = ui::accounts::AccountsScreen::new(account_repo.clone());
 
-    let saved_screen = SavedScreen::new(home_screen, settings_screen, accounts_screen);
+    let console_screen = ui::console::ConsoleScreen::new(process_repo.clone());
+
+    let saved_screen = SavedScreen::new(
+        home_screen,
+        instances_screen,
+        settings_screen,
+        accounts_screen,
+        console_screen,
+    );
 
     let state = State::new(theme, saved_screen);
 
-    (state, home_task.map(|home_msg| Message::Home(home_msg)))
+    (
+        state,
+        Task::batch([
+            home_task.map(Message::Home),
+            instances_task.map(Message::Instances),
+            settings_task.map(Message::Settings),
+        ]),
+    )
 }
 
 fn main() -> iced::Result {
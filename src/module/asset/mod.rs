@@ -0,0 +1,5 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod model;
+pub mod service;
@@ -0,0 +1,37 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Content of `assets/indexes/<id>.json`
+#[derive(Deserialize, Debug)]
+pub struct AssetIndexFile {
+    pub objects: HashMap<String, AssetObject>,
+    /// Old versions (1.6 - 1.7.2) read assets from `assets/virtual/<id>`
+    #[serde(default, rename = "virtual")]
+    pub is_virtual: bool,
+    /// Pre-1.6 versions read assets from `<game_dir>/resources`
+    #[serde(default)]
+    pub map_to_resources: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AssetObject {
+    pub hash: String,
+    pub size: u64,
+}
+
+impl AssetObject {
+    /// Relative path of object, eg. "ab/abcdef..."
+    /// Fails when the hash is too short, eg. in a corrupted index
+    pub fn path(&self) -> Result<String, String> {
+        let prefix = self
+            .hash
+            .get(..2)
+            .ok_or_else(|| format!("Invalid asset hash: {:?}", self.hash))?;
+
+        Ok(format!("{}/{}", prefix, self.hash))
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::module::asset::model::AssetIndexFile;
+use crate::module::download::entity::DownloadTask;
+use crate::module::download::repository::DownloadRepository;
+use crate::module::download::service::{DownloadService, MAX_CONCURRENT_DOWNLOADS, ProgressSender};
+use crate::module::launcher::model::AssetIndex;
+
+const RESOURCES_URL: &str = "https://resources.download.minecraft.net";
+
+pub struct AssetService {}
+
+impl AssetService {
+    /// Install asset index and every asset object
+    /// Returns directory used for `${game_assets}`
+    pub async fn prepare(
+        asset_index: &AssetIndex,
+        assets_dir: &Path,
+        game_dir: &Path,
+        full_check: bool,
+        progress: &ProgressSender,
+    ) -> Result<PathBuf, String> {
+        let index_id = asset_index.file_name()?;
+        let index = Self::prepare_index(asset_index, assets_dir, full_check).await?;
+        let objects_dir = assets_dir.join("objects");
+
+        let mut tasks = Vec::new();
+        for object in index.objects.values() {
+            let relative_path = object.path()?;
+            let object_path = objects_dir.join(&relative_path);
+            if DownloadRepository::is_valid(
+                &object_path,
+                Some(&object.hash),
+                Some(object.size),
+                full_check,
+            ) {
+                continue;
+            }
+
+            let url = format!("{}/{}", RESOURCES_URL, relative_path);
+            tasks.push(DownloadTask::new(
+                &url,
+                object_path,
+                Some(&object.hash),
+                Some(object.size),
+            ));
+        }
+
+        // Same object can be used by several names
+        tasks.sort_by(|a, b| a.path.cmp(&b.path));
+        tasks.dedup();
+
+        DownloadService::download_all(tasks, MAX_CONCURRENT_DOWNLOADS, progress).await?;
+
+        // Legacy versions can't read hashed objects, copy them with their real names
+        let legacy_dir = if index.map_to_resources {
+            game_dir.join("resources")
+        } else if index.is_virtual {
+            assets_dir.join("virtual").join(index_id)
+        } else {
+            return Ok(assets_dir.to_path_buf());
+        };
+
+        Self::materialize(&index, &objects_dir, &legacy_dir)?;
+
+        Ok(legacy_dir)
+    }
+
+    /// Read `assets/indexes/<id>.json`
+    /// Download if not found
+    async fn prepare_index(
+        asset_index: &AssetIndex,
+        assets_dir: &Path,
+        full_check: bool,
+    ) -> Result<AssetIndexFile, String> {
+        let index_path = assets_dir
+            .join("indexes")
+            .join(format!("{}.json", asset_index.file_name()?));
+        let sha1 = asset_index.sha1.as_deref();
+
+        if !DownloadRepository::is_valid(&index_path, sha1, asset_index.size, full_check) {
+            DownloadRepository::download_file(
+                &asset_index.url,
+                &index_path,
+                sha1,
+                asset_index.size,
+            )
+            .await?;
+        }
+
+        let content = fs::read_to_string(&index_path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&content).map_err(|e| e.to_string())
+    }
+
+    /// Copy objects into `target_dir` using their original names
+    fn materialize(
+        index: &AssetIndexFile,
+        objects_dir: &Path,
+        target_dir: &Path,
+    ) -> Result<(), String> {
+        for (name, object) in &index.objects {
+            let target_path = target_dir.join(name);
+
+            let up_to_date = fs::metadata(&target_path)
+                .map(|m| m.len() == object.size)
+                .unwrap_or(false);
+            if up_to_date {
+                continue;
+            }
+
+            if let Some(parent) = target_path.parent() {
+                fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+
+            fs::copy(objects_dir.join(object.path()?), &target_path).map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+}
@@ -3,23 +3,68 @@
 
 use serde::{Deserialize, Serialize};
 
+pub const DEFAULT_FABRIC_META_URL: &str = "https://meta.fabricmc.net";
+pub const DEFAULT_QUILT_META_URL: &str = "https://meta.quiltmc.org";
+
+/// Default `java_path` of older versions, written to every existing config
+const LEGACY_DEFAULT_JAVA_PATH: &str = "/usr/bin/java";
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
 pub struct AppConfig {
+    /// Maximum heap size in MB (-Xmx)
     pub allocated_ram: u32,
+    /// Initial heap size in MB (-Xms), 0 lets Java decide
+    pub min_ram: u32,
+    /// Java executable used for every version, empty to pick a runtime automatically
     pub java_path: String,
     pub language: String,
     pub theme: String,
     pub minecraft_root_dir: String,
+    /// Re-check SHA-1 of installed files before launch, not only their size
+    pub verify_file_hashes: bool,
+    /// Extra JVM arguments, quotes keep spaces inside an argument
+    pub jvm_arguments: String,
+    /// Extra game arguments appended after the version ones
+    pub game_arguments: String,
+    /// Environment variables of the game, eg. `__GL_THREADED_OPTIMIZATIONS=1 MESA_GL_VERSION_OVERRIDE=4.5`
+    pub environment_variables: String,
+    /// Command the game is started through, eg. `gamemoderun` or `prime-run mangohud`
+    pub wrapper_command: String,
+    /// Fabric meta server, eg. https://meta.fabricmc.net
+    pub fabric_meta_url: String,
+    /// Quilt meta server, eg. https://meta.quiltmc.org
+    pub quilt_meta_url: String,
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
             allocated_ram: 4096,
-            java_path: String::from("/usr/bin/java"),
+            min_ram: 0,
+            java_path: String::new(),
             minecraft_root_dir: String::from("~/.minecraft"),
             language: String::from("English"),
             theme: String::from("TokyoNight"),
+            verify_file_hashes: false,
+            jvm_arguments: String::new(),
+            game_arguments: String::new(),
+            environment_variables: String::new(),
+            wrapper_command: String::new(),
+            fabric_meta_url: String::from(DEFAULT_FABRIC_META_URL),
+            quilt_meta_url: String::from(DEFAULT_QUILT_META_URL),
         }
     }
 }
+
+impl AppConfig {
+    /// Update values saved by older versions
+    /// The old default Java is dropped so the runtime matching each version is picked
+    pub fn migrate(mut self) -> Self {
+        if self.java_path == LEGACY_DEFAULT_JAVA_PATH {
+            self.java_path.clear();
+        }
+
+        self
+    }
+}
@@ -12,12 +12,16 @@ impl ConfigRepository {
         #[cfg(debug_assertions)]
         {
             let path = PathBuf::from("../axlauncher.conf");
-            confy::load_path(&path).unwrap_or_default()
+            confy::load_path(&path)
+                .map(AppConfig::migrate)
+                .unwrap_or_default()
         }
 
         #[cfg(not(debug_assertions))]
         {
-            confy::load("axlauncher", None).unwrap_or_default()
+            confy::load("axlauncher", None)
+                .map(AppConfig::migrate)
+                .unwrap_or_default()
         }
     }
 
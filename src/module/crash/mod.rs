@@ -0,0 +1,7 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod model;
+pub mod repository;
+pub mod service;
+pub mod signature;
@@ -0,0 +1,43 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrashReportKind {
+    /// `crash-reports/crash-*.txt` written by the game
+    Game,
+    /// `hs_err_pid*.log` written by the JVM when it crashed itself
+    Jvm,
+}
+
+/// Header of a crash report
+#[derive(Debug, Clone)]
+pub struct CrashReport {
+    pub path: PathBuf,
+    pub kind: CrashReportKind,
+    /// eg. "Unexpected error" or "SIGSEGV (0xb) at pc=..."
+    pub description: Option<String>,
+    /// First line of the stack trace, or the problematic frame for JVM crashes
+    pub exception: Option<String>,
+    /// Mods blamed by the mod loader
+    pub suspected_mods: Vec<String>,
+    pub java_version: Option<String>,
+    pub jvm_flags: Option<String>,
+    /// Whole report, used to match known signatures
+    pub content: String,
+}
+
+/// Known reason of a crash with a hint for the user
+#[derive(Debug, Clone)]
+pub struct CrashCause {
+    pub title: String,
+    pub explanation: String,
+}
+
+/// Result of analyzing a crashed game
+#[derive(Debug, Clone, Default)]
+pub struct CrashAnalysis {
+    pub report: Option<CrashReport>,
+    pub cause: Option<CrashCause>,
+}
@@ -0,0 +1,190 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::module::crash::model::{CrashReport, CrashReportKind};
+
+pub struct CrashRepository {}
+
+impl CrashRepository {
+    /// Newest crash report of the game directory modified after `since`
+    /// Looks at `crash-reports/*.txt` and `hs_err_pid*.log`
+    pub fn find_latest(game_dir: &Path, since: SystemTime) -> Option<(PathBuf, CrashReportKind)> {
+        let game_reports = Self::list_files(&game_dir.join("crash-reports"), |name| {
+            name.ends_with(".txt")
+        })
+        .map(|path| (path, CrashReportKind::Game));
+
+        let jvm_reports = Self::list_files(game_dir, |name| {
+            name.starts_with("hs_err_pid") && name.ends_with(".log")
+        })
+        .map(|path| (path, CrashReportKind::Jvm));
+
+        game_reports
+            .chain(jvm_reports)
+            .filter_map(|(path, kind)| {
+                let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+                (modified >= since).then_some((modified, path, kind))
+            })
+            .max_by_key(|(modified, ..)| *modified)
+            .map(|(_, path, kind)| (path, kind))
+    }
+
+    pub fn read_report(path: &Path, kind: CrashReportKind) -> Result<CrashReport, String> {
+        let bytes = fs::read(path).map_err(|e| e.to_string())?;
+        let content = String::from_utf8_lossy(&bytes).into_owned();
+
+        Ok(match kind {
+            CrashReportKind::Game => Self::parse_game_report(path, content),
+            CrashReportKind::Jvm => Self::parse_jvm_report(path, content),
+        })
+    }
+
+    fn list_files<F: Fn(&str) -> bool>(
+        dir: &Path,
+        filter: F,
+    ) -> impl Iterator<Item = PathBuf> + use<F> {
+        fs::read_dir(dir)
+            .into_iter()
+            .flat_map(|entries| entries.flatten())
+            .filter(move |entry| entry.file_name().to_str().is_some_and(&filter))
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+    }
+
+    /// ```text
+    /// Description: Unexpected error
+    ///
+    /// java.lang.OutOfMemoryError: Java heap space
+    /// ...
+    /// Suspected Mods:
+    ///     Example Mod (examplemod), Version: 1.0
+    /// ...
+    ///     Java Version: 17.0.8, Eclipse Adoptium
+    ///     JVM Flags: 2 total; -Xmx2048M -Xms512M
+    /// ```
+    fn parse_game_report(path: &Path, content: String) -> CrashReport {
+        let lines: Vec<&str> = content.lines().collect();
+
+        let description_index = lines
+            .iter()
+            .position(|line| line.starts_with("Description:"));
+
+        let description =
+            description_index.map(|i| lines[i]["Description:".len()..].trim().to_owned());
+
+        // Stack trace starts after the blank line following the description
+        let exception = description_index.and_then(|i| {
+            lines[i + 1..]
+                .iter()
+                .map(|line| line.trim())
+                .find(|line| !line.is_empty())
+                .map(str::to_owned)
+        });
+
+        CrashReport {
+            path: path.to_path_buf(),
+            kind: CrashReportKind::Game,
+            description,
+            exception,
+            suspected_mods: Self::parse_suspected_mods(&lines),
+            java_version: Self::find_value(&lines, "Java Version:"),
+            jvm_flags: Self::find_value(&lines, "JVM Flags:"),
+            content,
+        }
+    }
+
+    /// Forge writes `Suspected Mod: NONE`, or one mod per indented line below the key
+    fn parse_suspected_mods(lines: &[&str]) -> Vec<String> {
+        let Some(index) = lines.iter().position(|line| {
+            let line = line.trim_start();
+            line.starts_with("Suspected Mod:") || line.starts_with("Suspected Mods:")
+        }) else {
+            return Vec::new();
+        };
+
+        let (_, inline) = lines[index].split_once(':').unwrap_or_default();
+        let inline = inline.trim();
+        if !inline.is_empty() {
+            return match inline {
+                "NONE" | "Unknown" => Vec::new(),
+                mods => mods.split(',').map(|m| m.trim().to_owned()).collect(),
+            };
+        }
+
+        let indent = Self::indent(lines[index]);
+        let mods = lines[index + 1..]
+            .iter()
+            .take_while(|line| !line.trim().is_empty() && Self::indent(line) > indent);
+
+        // Deeper lines hold details of the mod above (eg. issue tracker URL)
+        let mod_indent = mods.clone().map(|line| Self::indent(line)).min();
+        mods.filter(|line| Some(Self::indent(line)) == mod_indent)
+            .map(|line| line.trim().to_owned())
+            .collect()
+    }
+
+    /// ```text
+    /// # A fatal error has been detected by the Java Runtime Environment:
+    /// #
+    /// #  SIGSEGV (0xb) at pc=0x00007f..., pid=1234, tid=1235
+    /// #
+    /// # JRE version: OpenJDK Runtime Environment (17.0.8+7) (build 17.0.8+7)
+    /// # Problematic frame:
+    /// # C  [libGL.so.1+0x1234]
+    /// ...
+    /// jvm_args: -Xmx2048M -Xms512M
+    /// ```
+    fn parse_jvm_report(path: &Path, content: String) -> CrashReport {
+        let lines: Vec<&str> = content.lines().collect();
+        let comments: Vec<&str> = lines
+            .iter()
+            .take_while(|line| line.starts_with('#') || line.trim().is_empty())
+            .map(|line| line.trim_start_matches('#').trim())
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        let description = comments
+            .iter()
+            .skip_while(|line| !line.starts_with("A fatal error"))
+            .nth(1)
+            .map(|line| line.to_string());
+
+        let exception = comments
+            .iter()
+            .skip_while(|line| !line.starts_with("Problematic frame:"))
+            .nth(1)
+            .map(|line| line.to_string());
+
+        let java_version = comments
+            .iter()
+            .find_map(|line| line.strip_prefix("JRE version:"))
+            .map(|version| version.trim().to_owned());
+
+        CrashReport {
+            path: path.to_path_buf(),
+            kind: CrashReportKind::Jvm,
+            description,
+            exception,
+            suspected_mods: Vec::new(),
+            java_version,
+            jvm_flags: Self::find_value(&lines, "jvm_args:"),
+            content,
+        }
+    }
+
+    fn find_value(lines: &[&str], key: &str) -> Option<String> {
+        lines
+            .iter()
+            .find_map(|line| line.trim_start().strip_prefix(key))
+            .map(|value| value.trim().to_owned())
+    }
+
+    /// Count leading whitespace characters, crash reports indent with tabs
+    fn indent(line: &str) -> usize {
+        line.len() - line.trim_start().len()
+    }
+}
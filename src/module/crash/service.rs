@@ -0,0 +1,43 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::module::crash::model::{CrashAnalysis, CrashCause};
+use crate::module::crash::repository::CrashRepository;
+use crate::module::crash::signature::SIGNATURES;
+
+pub struct CrashService {}
+
+impl CrashService {
+    /// Find the crash report written since the game started and explain the crash
+    /// `output` is the end of the game output, used when no report was written
+    /// (eg. mod loader errors are only printed)
+    pub async fn analyze(
+        game_dir: PathBuf,
+        since: SystemTime,
+        output: Vec<String>,
+    ) -> CrashAnalysis {
+        tokio::task::spawn_blocking(move || {
+            let report = CrashRepository::find_latest(&game_dir, since)
+                .and_then(|(path, kind)| CrashRepository::read_report(&path, kind).ok());
+
+            let cause = report
+                .as_ref()
+                .and_then(|report| Self::find_cause(&report.content))
+                .or_else(|| Self::find_cause(&output.join("\n")));
+
+            CrashAnalysis { report, cause }
+        })
+        .await
+        .unwrap_or_default()
+    }
+
+    fn find_cause(content: &str) -> Option<CrashCause> {
+        SIGNATURES
+            .iter()
+            .find(|signature| signature.matches(content))
+            .map(|signature| signature.to_cause())
+    }
+}
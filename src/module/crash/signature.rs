@@ -0,0 +1,90 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::module::crash::model::CrashCause;
+
+/// Native frame the JVM crashed in, as written in hs_err_pid reports (eg. `# C  [nvoglv64.dll+0x1234]`)
+const PROBLEMATIC_FRAME: &str = "# C  [";
+
+/// Crash matching any of `patterns`, checked in order so more specific signatures come first
+pub struct CrashSignature {
+    pub patterns: &'static [&'static str],
+    /// Native libraries matched only when the JVM crashed inside them
+    /// Reports list every loaded library, so a plain substring would match most crashes
+    pub frames: &'static [&'static str],
+    pub title: &'static str,
+    pub explanation: &'static str,
+}
+
+impl CrashSignature {
+    pub fn matches(&self, content: &str) -> bool {
+        self.patterns
+            .iter()
+            .any(|pattern| content.contains(pattern))
+            || content
+                .lines()
+                .filter_map(|line| line.strip_prefix(PROBLEMATIC_FRAME))
+                .any(|frame| self.frames.iter().any(|library| frame.starts_with(library)))
+    }
+
+    pub fn to_cause(&self) -> CrashCause {
+        CrashCause {
+            title: self.title.to_owned(),
+            explanation: self.explanation.to_owned(),
+        }
+    }
+}
+
+pub const SIGNATURES: &[CrashSignature] = &[
+    CrashSignature {
+        patterns: &["java.lang.OutOfMemoryError", "Out of memory:"],
+        frames: &[],
+        title: "Out of memory",
+        explanation: "The game ran out of memory. Increase Maximum Memory in Settings, or remove heavy mods and resource packs.",
+    },
+    CrashSignature {
+        patterns: &[
+            "java.lang.UnsupportedClassVersionError",
+            "has been compiled by a more recent version of the Java Runtime",
+            "Unsupported major.minor version",
+        ],
+        frames: &[],
+        title: "Java version is too old",
+        explanation: "The game or one of its mods needs a newer Java. Select Automatic or a newer runtime in Settings.",
+    },
+    CrashSignature {
+        patterns: &[
+            "Unsupported class file major version",
+            "java.lang.ClassCastException: class jdk.internal.loader.ClassLoaders$AppClassLoader",
+        ],
+        frames: &[],
+        title: "Java version is too new",
+        explanation: "This version or its mod loader doesn't support the selected Java. Select Automatic or an older runtime in Settings.",
+    },
+    CrashSignature {
+        patterns: &[
+            "Incompatible mods found!",
+            "Mod resolution encountered an incompatible mod set",
+            "Missing or unsupported mandatory dependencies",
+            "MissingModsException",
+            "which is missing!",
+        ],
+        frames: &[],
+        title: "Missing mod dependency",
+        explanation: "A mod requires another mod, or another version of it. Install the dependencies listed in the console, or remove the mod.",
+    },
+    CrashSignature {
+        patterns: &[
+            "Pixel format not accelerated",
+            "GLFW error 65542",
+            "GLFW error 65543",
+            "No OpenGL context",
+            "WGL: The driver does not appear to support OpenGL",
+            "Could not create context",
+            "org.lwjgl.LWJGLException",
+        ],
+        frames: &["atio6axx.dll", "nvoglv64.dll", "ig9icd64.dll", "libGL.so"],
+        title: "Graphics driver failure",
+        explanation: "The graphics driver failed to provide OpenGL. Update the graphics driver, and make sure the game runs on a GPU with OpenGL support.",
+    },
+];
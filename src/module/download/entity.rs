@@ -0,0 +1,48 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::PathBuf;
+
+/// A single file to download
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadTask {
+    pub url: String,
+    pub path: PathBuf,
+    pub sha1: Option<String>,
+    pub size: Option<u64>,
+}
+
+impl DownloadTask {
+    pub fn new(url: &str, path: PathBuf, sha1: Option<&str>, size: Option<u64>) -> Self {
+        Self {
+            url: url.to_owned(),
+            path,
+            sha1: sha1.map(str::to_owned),
+            size,
+        }
+    }
+}
+
+/// Snapshot of a running batch of downloads
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DownloadProgress {
+    pub total_files: usize,
+    pub finished_files: usize,
+    pub total_bytes: u64,
+    pub downloaded_bytes: u64,
+    pub current_file: Option<String>,
+    pub errors: Vec<String>,
+}
+
+impl DownloadProgress {
+    /// Progress between 0.0 and 1.0, based on bytes when sizes are known
+    pub fn ratio(&self) -> f32 {
+        if self.total_bytes > 0 {
+            (self.downloaded_bytes as f64 / self.total_bytes as f64).min(1.0) as f32
+        } else if self.total_files > 0 {
+            self.finished_files as f32 / self.total_files as f32
+        } else {
+            0.0
+        }
+    }
+}
@@ -0,0 +1,6 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod entity;
+pub mod repository;
+pub mod service;
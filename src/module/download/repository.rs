@@ -0,0 +1,173 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use reqwest::{Client, StatusCode, header};
+use sha1::{Digest, Sha1};
+use sha2::Sha256;
+use tokio::io::AsyncWriteExt;
+
+use crate::module::download::entity::DownloadTask;
+
+pub struct DownloadRepository {}
+
+impl DownloadRepository {
+    /// Download file from url and verify it against sha1 and size
+    /// Bytes are written into `{path}.part` and only renamed to path once verified
+    pub async fn download_file(
+        url: &str,
+        path: &Path,
+        sha1: Option<&str>,
+        size: Option<u64>,
+    ) -> Result<(), String> {
+        let task = DownloadTask::new(url, path.to_path_buf(), sha1, size);
+        Self::download(&Client::new(), &task, |_| {}).await
+    }
+
+    /// Stream a task to disk, calling `on_bytes` for every received chunk
+    /// An existing `{path}.part` is resumed with an HTTP Range request
+    pub async fn download(
+        client: &Client,
+        task: &DownloadTask,
+        mut on_bytes: impl FnMut(u64),
+    ) -> Result<(), String> {
+        if let Some(parent) = task.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+
+        let part_path = Self::part_path(&task.path);
+        let existing = fs::metadata(&part_path).map(|m| m.len()).unwrap_or(0);
+
+        let mut request = client.get(&task.url);
+        if existing > 0 {
+            request = request.header(header::RANGE, format!("bytes={}-", existing));
+        }
+
+        let response = request.send().await.map_err(|e| e.to_string())?;
+
+        // Partial file is broken or already complete, start again
+        if response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            let _ = fs::remove_file(&part_path);
+            return Err(format!("Invalid partial download for {}", task.url));
+        }
+
+        let mut response = response.error_for_status().map_err(|e| e.to_string())?;
+
+        let (mut hasher, mut file, mut downloaded) =
+            if existing > 0 && response.status() == StatusCode::PARTIAL_CONTENT {
+                let hasher = Self::hash_file::<Sha1>(&part_path)?;
+                let file = tokio::fs::OpenOptions::new()
+                    .append(true)
+                    .open(&part_path)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                (hasher, file, existing)
+            } else {
+                let file = tokio::fs::File::create(&part_path)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                (Sha1::new(), file, 0)
+            };
+
+        while let Some(chunk) = response.chunk().await.map_err(|e| e.to_string())? {
+            hasher.update(&chunk);
+            file.write_all(&chunk).await.map_err(|e| e.to_string())?;
+            downloaded += chunk.len() as u64;
+            on_bytes(chunk.len() as u64);
+        }
+
+        file.flush().await.map_err(|e| e.to_string())?;
+        drop(file);
+
+        if let Some(expected) = task.size
+            && downloaded != expected
+        {
+            let _ = fs::remove_file(&part_path);
+            return Err(format!(
+                "Size mismatch for {}: expected {} bytes, got {}",
+                task.url, expected, downloaded
+            ));
+        }
+
+        if let Some(expected) = &task.sha1 {
+            let actual = Self::to_hex(&hasher.finalize());
+            if !actual.eq_ignore_ascii_case(expected) {
+                let _ = fs::remove_file(&part_path);
+                return Err(format!(
+                    "SHA-1 mismatch for {}: expected {}, got {}",
+                    task.url, expected, actual
+                ));
+            }
+        }
+
+        fs::rename(&part_path, &task.path).map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Check if an installed file is still valid
+    /// Size is always compared, SHA-1 only when `full_check` is enabled
+    pub fn is_valid(path: &Path, sha1: Option<&str>, size: Option<u64>, full_check: bool) -> bool {
+        let Ok(metadata) = fs::metadata(path) else {
+            return false;
+        };
+
+        if !metadata.is_file() {
+            return false;
+        }
+
+        if let Some(expected) = size
+            && metadata.len() != expected
+        {
+            return false;
+        }
+
+        if full_check && let Some(expected) = sha1 {
+            return Self::sha1_file(path)
+                .map(|actual| actual.eq_ignore_ascii_case(expected))
+                .unwrap_or(false);
+        }
+
+        true
+    }
+
+    /// Returns SHA-1 of file as lowercase hex string
+    pub fn sha1_file(path: &Path) -> Result<String, String> {
+        Ok(Self::to_hex(&Self::hash_file::<Sha1>(path)?.finalize()))
+    }
+
+    /// Returns SHA-256 of file as lowercase hex string
+    pub fn sha256_file(path: &Path) -> Result<String, String> {
+        Ok(Self::to_hex(&Self::hash_file::<Sha256>(path)?.finalize()))
+    }
+
+    /// Temporary path used while downloading
+    pub fn part_path(path: &Path) -> PathBuf {
+        let mut part = path.as_os_str().to_owned();
+        part.push(".part");
+        PathBuf::from(part)
+    }
+
+    fn hash_file<D: Digest>(path: &Path) -> Result<D, String> {
+        let mut file = fs::File::open(path).map_err(|e| e.to_string())?;
+        let mut hasher = D::new();
+        let mut buffer = [0u8; 64 * 1024];
+
+        loop {
+            let read = file.read(&mut buffer).map_err(|e| e.to_string())?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+
+        Ok(hasher)
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+use std::fs;
+use std::time::Duration;
+
+use futures_util::{Stream, StreamExt};
+use reqwest::Client;
+use tokio::sync::watch;
+
+use crate::module::download::entity::{DownloadProgress, DownloadTask};
+use crate::module::download::repository::DownloadRepository;
+
+pub type ProgressSender = watch::Sender<DownloadProgress>;
+
+/// Number of files downloaded at the same time
+pub const MAX_CONCURRENT_DOWNLOADS: usize = 16;
+
+/// Attempts for each file before giving up
+const MAX_ATTEMPTS: u32 = 4;
+
+/// Delay before first retry, doubled after every failure
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+pub struct DownloadService {}
+
+impl DownloadService {
+    /// Create a progress channel for a new batch of downloads
+    pub fn progress_channel() -> (ProgressSender, watch::Receiver<DownloadProgress>) {
+        watch::channel(DownloadProgress::default())
+    }
+
+    /// Turn a progress receiver into a stream for the UI
+    /// Stream ends when the sender is dropped
+    pub fn progress_stream(
+        receiver: watch::Receiver<DownloadProgress>,
+    ) -> impl Stream<Item = DownloadProgress> {
+        futures_util::stream::unfold(receiver, |mut receiver| async move {
+            receiver.changed().await.ok()?;
+            let progress = receiver.borrow_and_update().clone();
+            Some((progress, receiver))
+        })
+    }
+
+    /// Download all tasks with a bounded pool of concurrent requests
+    /// Progress is published to `progress` while downloading
+    /// Returns an error listing every file that still failed after retries
+    pub async fn download_all(
+        mut tasks: Vec<DownloadTask>,
+        concurrency: usize,
+        progress: &ProgressSender,
+    ) -> Result<(), String> {
+        // The same file can be listed twice (eg. by a parent and a child version),
+        // concurrent downloads of it would write the same `.part` file
+        let mut paths = HashSet::new();
+        tasks.retain(|task| paths.insert(task.path.clone()));
+
+        if tasks.is_empty() {
+            return Ok(());
+        }
+
+        progress.send_modify(|p| {
+            p.total_files += tasks.len();
+            p.total_bytes += tasks.iter().filter_map(|t| t.size).sum::<u64>();
+        });
+
+        let client = Client::new();
+
+        let errors: Vec<String> = futures_util::stream::iter(tasks)
+            .map(|task| {
+                let client = &client;
+                async move { Self::download_with_retry(client, &task, progress).await }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .filter_map(|result| async move { result.err() })
+            .collect()
+            .await;
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} file(s) failed to download:\n{}",
+                errors.len(),
+                errors.join("\n")
+            ))
+        }
+    }
+
+    async fn download_with_retry(
+        client: &Client,
+        task: &DownloadTask,
+        progress: &ProgressSender,
+    ) -> Result<(), String> {
+        let file_name = task
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| task.url.clone());
+
+        // Bytes of this task already reported to progress
+        let mut reported: u64 = 0;
+        let mut delay = RETRY_BASE_DELAY;
+        let mut attempt = 1;
+
+        loop {
+            progress.send_modify(|p| p.current_file = Some(file_name.clone()));
+
+            let result = DownloadRepository::download(client, task, |bytes| {
+                reported += bytes;
+                progress.send_modify(|p| p.downloaded_bytes += bytes);
+            })
+            .await;
+
+            match result {
+                Ok(()) => {
+                    progress.send_modify(|p| p.finished_files += 1);
+                    return Ok(());
+                }
+                Err(e) => {
+                    // Only bytes kept in the partial file will be resumed
+                    let kept = fs::metadata(DownloadRepository::part_path(&task.path))
+                        .map(|m| m.len())
+                        .unwrap_or(0);
+                    progress.send_modify(|p| {
+                        p.downloaded_bytes = (p.downloaded_bytes + kept).saturating_sub(reported)
+                    });
+                    reported = kept;
+
+                    if attempt >= MAX_ATTEMPTS {
+                        let error = format!("{}: {}", file_name, e);
+                        progress.send_modify(|p| {
+                            p.finished_files += 1;
+                            p.errors.push(error.clone());
+                        });
+                        return Err(error);
+                    }
+
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::module::config::model::AppConfig;
+
+/// Directories created inside every instance
+pub const INSTANCE_SUBDIRS: [&str; 4] = ["mods", "saves", "config", "resourcepacks"];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ModLoader {
+    #[default]
+    Vanilla,
+    Fabric,
+    Quilt,
+    Forge,
+    NeoForge,
+}
+
+impl ModLoader {
+    pub const ALL: [Self; 5] = [
+        Self::Vanilla,
+        Self::Fabric,
+        Self::Quilt,
+        Self::Forge,
+        Self::NeoForge,
+    ];
+}
+
+impl fmt::Display for ModLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Vanilla => "Vanilla",
+            Self::Fabric => "Fabric",
+            Self::Quilt => "Quilt",
+            Self::Forge => "Forge",
+            Self::NeoForge => "NeoForge",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Settings of an instance replacing the global ones, None keeps the global value
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct InstanceOverrides {
+    pub java_path: Option<String>,
+    /// Maximum heap size in MB (-Xmx)
+    pub allocated_ram: Option<u32>,
+    /// Initial heap size in MB (-Xms)
+    pub min_ram: Option<u32>,
+    /// Replaces the global extra JVM arguments
+    pub jvm_arguments: Option<String>,
+    pub window_width: Option<u32>,
+    pub window_height: Option<u32>,
+}
+
+impl InstanceOverrides {
+    /// Apply overrides on top of the global config
+    pub fn apply(&self, config: &mut AppConfig) {
+        if let Some(java_path) = &self.java_path {
+            config.java_path = java_path.clone();
+        }
+        if let Some(allocated_ram) = self.allocated_ram {
+            config.allocated_ram = allocated_ram;
+        }
+        if let Some(min_ram) = self.min_ram {
+            config.min_ram = min_ram;
+        }
+        if let Some(jvm_arguments) = &self.jvm_arguments {
+            config.jvm_arguments = jvm_arguments.clone();
+        }
+    }
+
+    /// Window size passed with `--width` and `--height`
+    pub fn resolution(&self) -> Option<(u32, u32)> {
+        self.window_width.zip(self.window_height)
+    }
+}
+
+/// Content of `instance.json`
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct Instance {
+    /// Name of the instance directory, used as process id
+    #[serde(skip)]
+    pub id: String,
+    /// Instance directory, also the game directory
+    #[serde(skip)]
+    pub path: PathBuf,
+
+    pub name: String,
+    /// Version id launched, eg. "1.20.1"
+    pub version: String,
+    pub loader: ModLoader,
+    pub loader_version: Option<String>,
+    /// Icon file name inside the instance directory
+    pub icon: Option<String>,
+    pub notes: String,
+    pub overrides: InstanceOverrides,
+}
+
+impl Instance {
+    pub fn icon_path(&self) -> Option<PathBuf> {
+        self.icon.as_ref().map(|icon| self.path.join(icon))
+    }
+}
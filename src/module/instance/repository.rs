@@ -0,0 +1,70 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs;
+use std::path::Path;
+
+use crate::module::instance::model::Instance;
+
+/// Metadata file inside every instance directory
+pub const INSTANCE_FILE: &str = "instance.json";
+
+pub struct InstanceRepository {}
+
+impl InstanceRepository {
+    /// Read every instance of `instances_dir`, directories without a valid `instance.json` are skipped
+    pub fn list(instances_dir: &Path) -> Vec<Instance> {
+        let Ok(entries) = fs::read_dir(instances_dir) else {
+            return Vec::new();
+        };
+
+        let mut instances: Vec<Instance> = entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| Self::read(&entry.path()).ok())
+            .collect();
+
+        instances.sort_by_key(|instance| instance.name.to_lowercase());
+        instances
+    }
+
+    pub fn read(dir: &Path) -> Result<Instance, String> {
+        let path = dir.join(INSTANCE_FILE);
+        let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        let mut instance: Instance =
+            serde_json::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        instance.id = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        instance.path = dir.to_path_buf();
+
+        Ok(instance)
+    }
+
+    pub fn save(instance: &Instance) -> Result<(), String> {
+        fs::create_dir_all(&instance.path).map_err(|e| e.to_string())?;
+
+        let content = serde_json::to_string_pretty(instance).map_err(|e| e.to_string())?;
+        fs::write(instance.path.join(INSTANCE_FILE), content).map_err(|e| e.to_string())
+    }
+
+    /// Copy a directory recursively, symlinks are copied as the file they point to
+    pub fn copy_dir(source: &Path, dest: &Path) -> Result<(), String> {
+        fs::create_dir_all(dest).map_err(|e| e.to_string())?;
+
+        for entry in fs::read_dir(source).map_err(|e| e.to_string())? {
+            let entry = entry.map_err(|e| e.to_string())?;
+            let target = dest.join(entry.file_name());
+
+            if entry.path().is_dir() {
+                Self::copy_dir(&entry.path(), &target)?;
+            } else {
+                fs::copy(entry.path(), target).map_err(|e| e.to_string())?;
+            }
+        }
+
+        Ok(())
+    }
+}
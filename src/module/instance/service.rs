@@ -0,0 +1,149 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::module::instance::model::{INSTANCE_SUBDIRS, Instance, ModLoader};
+use crate::module::instance::repository::InstanceRepository;
+use crate::module::launcher::service::LauncherService;
+
+pub struct InstanceService {}
+
+impl InstanceService {
+    /// Directory holding every instance, `{minecraft_root_dir}/instances`
+    pub fn instances_dir() -> Result<PathBuf, String> {
+        let root_dir = LauncherService::get_minecraft_root_dir().map_err(|e| e.to_string())?;
+        Ok(root_dir.join("instances"))
+    }
+
+    pub fn list() -> Result<Vec<Instance>, String> {
+        Ok(InstanceRepository::list(&Self::instances_dir()?))
+    }
+
+    /// Create an instance with empty game directories
+    pub fn create(name: &str, version: &str, loader: ModLoader) -> Result<Instance, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Instance name is empty".to_owned());
+        }
+
+        let path = Self::unique_dir(&Self::instances_dir()?, name);
+        for subdir in INSTANCE_SUBDIRS {
+            fs::create_dir_all(path.join(subdir)).map_err(|e| e.to_string())?;
+        }
+
+        let instance = Instance {
+            id: Self::dir_name(&path),
+            path,
+            name: name.to_owned(),
+            version: version.to_owned(),
+            loader,
+            ..Default::default()
+        };
+        InstanceRepository::save(&instance)?;
+
+        Ok(instance)
+    }
+
+    /// Only the display name changes, the directory keeps its name
+    pub fn rename(instance: &mut Instance, name: &str) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Instance name is empty".to_owned());
+        }
+
+        instance.name = name.to_owned();
+        InstanceRepository::save(instance)
+    }
+
+    pub fn save(instance: &Instance) -> Result<(), String> {
+        InstanceRepository::save(instance)
+    }
+
+    /// Copy an instance with its worlds, mods and settings
+    pub async fn duplicate(instance: Instance) -> Result<Instance, String> {
+        tokio::task::spawn_blocking(move || {
+            let name = format!("{} (copy)", instance.name);
+            let parent = instance
+                .path
+                .parent()
+                .ok_or_else(|| "Invalid instance directory".to_owned())?;
+            let path = Self::unique_dir(parent, &name);
+
+            InstanceRepository::copy_dir(&instance.path, &path)?;
+
+            let copy = Instance {
+                id: Self::dir_name(&path),
+                path,
+                name,
+                ..instance
+            };
+            InstanceRepository::save(&copy)?;
+
+            Ok(copy)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
+    /// Delete an instance directory with everything inside
+    pub async fn delete(instance: Instance) -> Result<(), String> {
+        tokio::task::spawn_blocking(move || {
+            fs::remove_dir_all(&instance.path).map_err(|e| e.to_string())
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+
+    /// Copy an image into the instance directory and use it as icon
+    pub fn set_icon(instance: &mut Instance, image: &Path) -> Result<(), String> {
+        let extension = image
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase())
+            .unwrap_or_else(|| "png".to_owned());
+        let icon = format!("icon.{}", extension);
+
+        // Old icon is only removed once the new one is in place
+        fs::copy(image, instance.path.join(&icon)).map_err(|e| e.to_string())?;
+        if let Some(old_icon) = instance.icon.replace(icon)
+            && instance.icon.as_ref() != Some(&old_icon)
+        {
+            let _ = fs::remove_file(instance.path.join(old_icon));
+        }
+
+        InstanceRepository::save(instance)
+    }
+
+    /// Directory name derived from the instance name, eg. "My Pack (copy)" -> "my-pack-copy"
+    /// A number is appended when the directory already exists
+    fn unique_dir(parent: &Path, name: &str) -> PathBuf {
+        let mut slug = String::new();
+        for c in name.chars() {
+            if c.is_alphanumeric() {
+                slug.extend(c.to_lowercase());
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        let slug = match slug.trim_end_matches('-') {
+            "" => "instance",
+            slug => slug,
+        };
+
+        let mut path = parent.join(slug);
+        let mut counter = 2;
+        while path.exists() {
+            path = parent.join(format!("{}-{}", slug, counter));
+            counter += 1;
+        }
+
+        path
+    }
+
+    fn dir_name(path: &Path) -> String {
+        path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// Mojang `java-runtime/all.json`, maps platform to components to runtimes
+pub type RuntimeIndex = HashMap<String, HashMap<String, Vec<RuntimeEntry>>>;
+
+#[derive(Deserialize, Debug)]
+pub struct RuntimeEntry {
+    pub manifest: RuntimeDownload,
+    pub version: RuntimeVersion,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RuntimeVersion {
+    /// eg. "17.0.8"
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RuntimeDownload {
+    pub url: String,
+    pub sha1: Option<String>,
+    pub size: Option<u64>,
+}
+
+/// Every file of a runtime component, keyed by relative path
+#[derive(Deserialize, Debug)]
+pub struct RuntimeManifest {
+    pub files: HashMap<String, RuntimeFile>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum RuntimeFile {
+    Directory,
+    File {
+        #[serde(default)]
+        executable: bool,
+        downloads: RuntimeFileDownloads,
+    },
+    Link {
+        target: String,
+    },
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RuntimeFileDownloads {
+    pub raw: RuntimeDownload,
+}
+
+/// Adoptium image type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageType {
+    Jre,
+    Jdk,
+}
+
+impl ImageType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Jre => "jre",
+            Self::Jdk => "jdk",
+        }
+    }
+}
+
+/// Release returned by Adoptium `assets/latest` API
+#[derive(Deserialize, Debug)]
+pub struct AdoptiumRelease {
+    pub binary: AdoptiumBinary,
+    /// eg. "jdk-17.0.8+7"
+    pub release_name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AdoptiumBinary {
+    pub package: AdoptiumPackage,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AdoptiumPackage {
+    /// File name, eg. "OpenJDK17U-jre_x64_linux_hotspot_17.0.8_7.tar.gz"
+    pub name: String,
+    pub link: String,
+    /// SHA-256 of the archive
+    pub checksum: Option<String>,
+    pub size: Option<u64>,
+}
+
+/// Java installation found on the system
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JavaInstallation {
+    /// Path to java executable
+    pub path: PathBuf,
+    /// Full version, eg. "1.8.0_402" or "17.0.8"
+    pub version: String,
+    pub major_version: u32,
+    pub vendor: Option<String>,
+    pub arch: Option<String>,
+}
+
+impl JavaInstallation {
+    /// Returns major version of a Java version string ("1.8.0_402" is 8, "17.0.8" is 17)
+    pub fn parse_major_version(version: &str) -> Option<u32> {
+        let mut parts = version.split(['.', '_', '-', '+']);
+        let first = parts.next()?;
+        let major = if first == "1" { parts.next()? } else { first };
+
+        major.parse().ok()
+    }
+}
+
+impl fmt::Display for JavaInstallation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Java {}", self.version)?;
+
+        let details: Vec<&str> = [self.vendor.as_deref(), self.arch.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect();
+        if !details.is_empty() {
+            write!(f, " ({})", details.join(", "))?;
+        }
+
+        write!(f, " - {}", self.path.to_string_lossy())
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::de::DeserializeOwned;
+
+use crate::module::download::entity::DownloadTask;
+use crate::module::download::repository::DownloadRepository;
+use crate::module::download::service::{DownloadService, ProgressSender};
+use crate::module::java::model::{
+    AdoptiumPackage, AdoptiumRelease, ImageType, JavaInstallation, RuntimeIndex, RuntimeManifest,
+};
+
+const ADOPTIUM_API_URL: &str = "https://api.adoptium.net/v3";
+
+const RUNTIME_INDEX_URL: &str = "https://launchermeta.mojang.com/v1/products/java-runtime/2ec0cc96c44e5a76b9c8b7c39df7210883d12871/all.json";
+
+/// Directory levels searched below a runtime directory for `bin/java`
+const MAX_SCAN_DEPTH: usize = 4;
+
+/// Name of java executable for current OS
+#[cfg(target_os = "windows")]
+pub const JAVA_EXECUTABLE: &str = "java.exe";
+#[cfg(not(target_os = "windows"))]
+pub const JAVA_EXECUTABLE: &str = "java";
+
+pub struct JavaRepository {}
+
+impl JavaRepository {
+    /// Returns platform name used by Mojang runtime index
+    pub fn mojang_platform() -> Option<&'static str> {
+        match (std::env::consts::OS, std::env::consts::ARCH) {
+            ("linux", "x86_64") => Some("linux"),
+            ("linux", "x86") => Some("linux-i386"),
+            ("macos", "x86_64") => Some("mac-os"),
+            ("macos", "aarch64") => Some("mac-os-arm64"),
+            ("windows", "x86_64") => Some("windows-x64"),
+            ("windows", "x86") => Some("windows-x86"),
+            ("windows", "aarch64") => Some("windows-arm64"),
+            _ => None,
+        }
+    }
+
+    pub async fn get_runtime_index() -> Result<RuntimeIndex, String> {
+        Self::get_json(RUNTIME_INDEX_URL).await
+    }
+
+    pub async fn get_runtime_manifest(url: &str) -> Result<RuntimeManifest, String> {
+        Self::get_json(url).await
+    }
+
+    /// Returns latest Adoptium release of the major version for current OS and architecture
+    /// None when Adoptium doesn't build the image for current platform (eg. Java 8 JRE on macOS arm64)
+    pub async fn get_adoptium_release(
+        major_version: u32,
+        image_type: ImageType,
+    ) -> Result<Option<AdoptiumRelease>, String> {
+        let (os, arch) = Self::adoptium_platform()?;
+        let url = format!(
+            "{}/assets/latest/{}/hotspot?os={}&architecture={}&image_type={}&vendor=eclipse",
+            ADOPTIUM_API_URL,
+            major_version,
+            os,
+            arch,
+            image_type.as_str()
+        );
+
+        let releases: Vec<AdoptiumRelease> = Self::get_json(&url).await?;
+        Ok(releases.into_iter().next())
+    }
+
+    /// Download an Adoptium package, verify its checksum and extract it into `dest_dir`
+    pub async fn download_package(
+        package: &AdoptiumPackage,
+        dest_dir: &Path,
+        progress: &ProgressSender,
+    ) -> Result<(), String> {
+        let archive_path = dest_dir.with_file_name(&package.name);
+        let task = DownloadTask::new(&package.link, archive_path.clone(), None, package.size);
+        DownloadService::download_all(vec![task], 1, progress).await?;
+
+        if let Some(expected) = &package.checksum {
+            let actual = DownloadRepository::sha256_file(&archive_path)?;
+            if !actual.eq_ignore_ascii_case(expected) {
+                let _ = fs::remove_file(&archive_path);
+                return Err(format!(
+                    "SHA-256 mismatch for {}: expected {}, got {}",
+                    package.name, expected, actual
+                ));
+            }
+        }
+
+        let result = Self::extract_archive(&archive_path, dest_dir);
+
+        // Delete temp file
+        let _ = fs::remove_file(archive_path);
+
+        result
+    }
+
+    /// Extract a tar.gz or zip archive, format is detected from file content
+    pub fn extract_archive(archive_path: &Path, dest_dir: &Path) -> Result<(), String> {
+        fs::create_dir_all(dest_dir).map_err(|e| e.to_string())?;
+
+        let mut magic = [0u8; 4];
+        let mut file = fs::File::open(archive_path).map_err(|e| e.to_string())?;
+        file.read_exact(&mut magic).map_err(|e| e.to_string())?;
+        file.seek(SeekFrom::Start(0)).map_err(|e| e.to_string())?;
+
+        match magic {
+            [b'P', b'K', 3, 4] => {
+                let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+                archive.extract(dest_dir).map_err(|e| e.to_string())
+            }
+            [0x1f, 0x8b, ..] => {
+                let tar = flate2::read::GzDecoder::new(file);
+                let mut archive = tar::Archive::new(tar);
+                archive.unpack(dest_dir).map_err(|e| e.to_string())
+            }
+            _ => Err(format!(
+                "Unsupported archive format: {}",
+                archive_path.to_string_lossy()
+            )),
+        }
+    }
+
+    /// Returns os and architecture names used by Adoptium API
+    fn adoptium_platform() -> Result<(&'static str, &'static str), String> {
+        let os = match std::env::consts::OS {
+            "macos" => "mac",
+            os @ ("linux" | "windows") => os,
+            os => return Err(format!("Unsupported operating system: {}", os)),
+        };
+
+        let arch = match std::env::consts::ARCH {
+            "x86_64" => "x64",
+            "x86" => "x32",
+            arch @ ("aarch64" | "arm") => arch,
+            arch => return Err(format!("Unsupported architecture: {}", arch)),
+        };
+
+        Ok((os, arch))
+    }
+
+    async fn get_json<T: DeserializeOwned>(url: &str) -> Result<T, String> {
+        reqwest::get(url)
+            .await
+            .map_err(|e| e.to_string())?
+            .error_for_status()
+            .map_err(|e| e.to_string())?
+            .json::<T>()
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Read version, vendor and architecture of a java executable
+    /// `release` file of the Java home is used when present, otherwise java is run
+    pub fn read_installation(java: &Path) -> Result<JavaInstallation, String> {
+        if !java.is_file() {
+            return Err(format!("{} is not a file", java.to_string_lossy()));
+        }
+
+        let release = java
+            .parent()
+            .and_then(Path::parent)
+            .and_then(|home| fs::read_to_string(home.join("release")).ok());
+
+        let properties = match release {
+            Some(release) => Self::parse_release_file(&release),
+            None => Self::query_properties(java)?,
+        };
+
+        Self::to_installation(java, &properties)
+    }
+
+    /// Run java and read its system properties
+    pub fn query_installation(java: &Path) -> Result<JavaInstallation, String> {
+        let properties = Self::query_properties(java)?;
+        Self::to_installation(java, &properties)
+    }
+
+    /// Parse `KEY="value"` lines of `release` file into java property names
+    fn parse_release_file(content: &str) -> HashMap<String, String> {
+        content
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .filter_map(|(key, value)| {
+                let property = match key.trim() {
+                    "JAVA_VERSION" => "java.version",
+                    "IMPLEMENTOR" => "java.vendor",
+                    "OS_ARCH" => "os.arch",
+                    _ => return None,
+                };
+                Some((
+                    property.to_owned(),
+                    value.trim().trim_matches('"').to_owned(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Properties are printed to stderr as `    key = value`
+    fn query_properties(java: &Path) -> Result<HashMap<String, String>, String> {
+        let output = Command::new(java)
+            .args(["-XshowSettings:properties", "-version"])
+            .output()
+            .map_err(|e| format!("Failed to run {}: {}", java.to_string_lossy(), e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "{} exited with {}",
+                java.to_string_lossy(),
+                output.status
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .filter_map(|line| line.split_once(" = "))
+            .map(|(key, value)| (key.trim().to_owned(), value.trim().to_owned()))
+            .collect())
+    }
+
+    fn to_installation(
+        java: &Path,
+        properties: &HashMap<String, String>,
+    ) -> Result<JavaInstallation, String> {
+        let version = properties
+            .get("java.version")
+            .ok_or_else(|| format!("Unknown Java version: {}", java.to_string_lossy()))?;
+        let major_version = JavaInstallation::parse_major_version(version)
+            .ok_or_else(|| format!("Invalid Java version: {}", version))?;
+
+        Ok(JavaInstallation {
+            path: java.to_path_buf(),
+            version: version.clone(),
+            major_version,
+            vendor: properties.get("java.vendor").cloned(),
+            arch: properties.get("os.arch").cloned(),
+        })
+    }
+
+    /// Find java executable inside a runtime directory
+    /// Shallowest `bin/java` wins, archives may nest it (eg. jdk-17.0.8+7-jre/Contents/Home/bin)
+    pub fn find_executable(runtime_dir: &Path) -> Option<PathBuf> {
+        let mut dirs = vec![runtime_dir.to_path_buf()];
+
+        for _ in 0..=MAX_SCAN_DEPTH {
+            let java = dirs
+                .iter()
+                .map(|dir| dir.join("bin").join(JAVA_EXECUTABLE))
+                .find(|path| path.is_file());
+            if java.is_some() {
+                return java;
+            }
+
+            let mut children: Vec<PathBuf> = dirs
+                .iter()
+                .filter_map(|dir| fs::read_dir(dir).ok())
+                .flat_map(|entries| entries.flatten().map(|entry| entry.path()))
+                .filter(|path| path.is_dir())
+                .collect();
+
+            // Keep result stable between runs
+            children.sort();
+            dirs = children;
+        }
+
+        None
+    }
+}
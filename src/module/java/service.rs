@@ -0,0 +1,323 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::module::download::entity::DownloadTask;
+use crate::module::download::repository::DownloadRepository;
+use crate::module::download::service::{DownloadService, MAX_CONCURRENT_DOWNLOADS, ProgressSender};
+use crate::module::java::model::{
+    ImageType, JavaInstallation, RuntimeEntry, RuntimeFile, RuntimeManifest,
+};
+use crate::module::java::repository::{JAVA_EXECUTABLE, JavaRepository};
+use crate::module::launcher::model::JavaVersion;
+
+/// File storing installed runtime version inside runtime directory
+const VERSION_MARKER: &str = ".version";
+
+pub struct JavaService {}
+
+impl JavaService {
+    /// Directory holding runtimes installed by the launcher
+    pub fn runtime_root() -> Result<PathBuf, String> {
+        let current_dir = std::env::current_dir().map_err(|e| e.to_string())?;
+        Ok(current_dir.join("..").join("java_runtime"))
+    }
+
+    /// Find every Java installation on the system, newest major version first
+    /// Searches `JAVA_HOME`, `PATH`, common install directories, SDKMAN and launcher runtimes
+    pub async fn discover() -> Vec<JavaInstallation> {
+        tokio::task::spawn_blocking(Self::discover_blocking)
+            .await
+            .unwrap_or_default()
+    }
+
+    /// Check that a java executable runs and read its version
+    pub async fn test(java: PathBuf) -> Result<JavaInstallation, String> {
+        tokio::task::spawn_blocking(move || JavaRepository::query_installation(&java))
+            .await
+            .map_err(|e| e.to_string())?
+    }
+
+    fn discover_blocking() -> Vec<JavaInstallation> {
+        let mut candidates = Vec::new();
+
+        if let Some(java_home) = std::env::var_os("JAVA_HOME") {
+            candidates.extend(JavaRepository::find_executable(Path::new(&java_home)));
+        }
+
+        if let Some(path) = std::env::var_os("PATH") {
+            candidates.extend(
+                std::env::split_paths(&path)
+                    .map(|dir| dir.join(JAVA_EXECUTABLE))
+                    .filter(|java| java.is_file()),
+            );
+        }
+
+        // Every child of these directories is a Java home
+        for root in Self::search_roots() {
+            let Ok(entries) = fs::read_dir(&root) else {
+                continue;
+            };
+
+            candidates.extend(
+                entries
+                    .flatten()
+                    .filter(|entry| entry.path().is_dir())
+                    .filter_map(|entry| JavaRepository::find_executable(&entry.path())),
+            );
+        }
+
+        // Same installation is often reachable through symlinks (eg. /usr/bin/java)
+        let mut seen = HashSet::new();
+        let mut installations: Vec<JavaInstallation> = candidates
+            .into_iter()
+            .filter(|java| seen.insert(fs::canonicalize(java).unwrap_or_else(|_| java.clone())))
+            .filter_map(|java| JavaRepository::read_installation(&java).ok())
+            .collect();
+
+        installations.sort_by(|a, b| {
+            b.major_version
+                .cmp(&a.major_version)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+
+        installations
+    }
+
+    fn search_roots() -> Vec<PathBuf> {
+        let mut roots = vec![
+            PathBuf::from("/usr/lib/jvm"),
+            PathBuf::from("/usr/java"),
+            PathBuf::from("/opt"),
+            PathBuf::from("/Library/Java/JavaVirtualMachines"),
+            PathBuf::from("C:\\Program Files\\Java"),
+            PathBuf::from("C:\\Program Files\\Eclipse Adoptium"),
+        ];
+
+        if let Some(home) = std::env::home_dir() {
+            roots.push(home.join(".sdkman/candidates/java"));
+            roots.push(home.join(".jdks"));
+        }
+
+        if let Ok(runtime_root) = Self::runtime_root() {
+            roots.push(runtime_root);
+        }
+
+        roots
+    }
+
+    /// Directory of a runtime component, eg. `java_runtime/java-runtime-gamma`
+    pub fn runtime_dir(runtime_root: &Path, component: &str) -> PathBuf {
+        runtime_root.join(component)
+    }
+
+    /// Version written by the last install, None if not installed by the launcher
+    pub fn installed_version(runtime_dir: &Path) -> Option<String> {
+        fs::read_to_string(runtime_dir.join(VERSION_MARKER))
+            .ok()
+            .map(|v| v.trim().to_owned())
+    }
+
+    /// Returns java executable of the runtime required by a version
+    /// Mojang runtime of the component is installed or updated into `{runtime_root}/{component}`,
+    /// Adoptium is used when Mojang doesn't provide the component for current platform
+    pub async fn ensure_runtime(
+        java_version: &JavaVersion,
+        runtime_root: &Path,
+        full_check: bool,
+        progress: &ProgressSender,
+    ) -> Result<PathBuf, String> {
+        let runtime_dir = Self::runtime_dir(runtime_root, &java_version.component);
+        let installed = JavaRepository::find_executable(&runtime_dir);
+
+        let entry = match Self::find_mojang_runtime(&java_version.component).await {
+            Ok(entry) => entry,
+            // Offline, keep using installed runtime
+            Err(e) => return installed.ok_or(e),
+        };
+
+        let java = match entry {
+            Some(entry) => {
+                let up_to_date = Self::installed_version(&runtime_dir).as_deref()
+                    == Some(entry.version.name.as_str());
+
+                match installed {
+                    Some(java) if up_to_date && !full_check => java,
+                    _ => {
+                        Self::install_mojang_runtime(&entry, &runtime_dir, full_check, progress)
+                            .await?
+                    }
+                }
+            }
+            None => match installed {
+                Some(java) => java,
+                None => {
+                    Self::install_adoptium_runtime(
+                        java_version.major_version,
+                        &runtime_dir,
+                        progress,
+                    )
+                    .await?
+                }
+            },
+        };
+
+        Ok(java)
+    }
+
+    /// Look up newest runtime of component for current platform in Mojang runtime index
+    async fn find_mojang_runtime(component: &str) -> Result<Option<RuntimeEntry>, String> {
+        let Some(platform) = JavaRepository::mojang_platform() else {
+            return Ok(None);
+        };
+
+        let mut index = JavaRepository::get_runtime_index().await?;
+
+        Ok(index
+            .get_mut(platform)
+            .and_then(|components| components.remove(component))
+            .and_then(|entries| entries.into_iter().next()))
+    }
+
+    /// Install every file of a Mojang runtime manifest
+    /// Only missing or invalid files are downloaded
+    async fn install_mojang_runtime(
+        entry: &RuntimeEntry,
+        runtime_dir: &Path,
+        full_check: bool,
+        progress: &ProgressSender,
+    ) -> Result<PathBuf, String> {
+        let manifest = JavaRepository::get_runtime_manifest(&entry.manifest.url).await?;
+
+        // Marker is only written back once every file is in place
+        let _ = fs::remove_file(runtime_dir.join(VERSION_MARKER));
+
+        let mut tasks = Vec::new();
+        for (name, file) in &manifest.files {
+            let path = runtime_dir.join(name);
+
+            match file {
+                RuntimeFile::Directory => {
+                    fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+                }
+                RuntimeFile::File { downloads, .. } => {
+                    let raw = &downloads.raw;
+                    let sha1 = raw.sha1.as_deref();
+
+                    if !DownloadRepository::is_valid(&path, sha1, raw.size, full_check) {
+                        tasks.push(DownloadTask::new(&raw.url, path, sha1, raw.size));
+                    }
+                }
+                RuntimeFile::Link { .. } => {}
+            }
+        }
+
+        DownloadService::download_all(tasks, MAX_CONCURRENT_DOWNLOADS, progress).await?;
+
+        Self::finish_mojang_runtime(&manifest, runtime_dir)?;
+
+        fs::write(runtime_dir.join(VERSION_MARKER), &entry.version.name)
+            .map_err(|e| e.to_string())?;
+
+        Self::find_java(runtime_dir)
+    }
+
+    /// Recreate symlinks and executable bits once files are downloaded
+    fn finish_mojang_runtime(manifest: &RuntimeManifest, runtime_dir: &Path) -> Result<(), String> {
+        for (name, file) in &manifest.files {
+            let path = runtime_dir.join(name);
+
+            match file {
+                RuntimeFile::File {
+                    executable: true, ..
+                } => Self::set_executable(&path)?,
+                RuntimeFile::Link { target } => Self::create_link(target, &path)?,
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Install latest Adoptium runtime of the major version
+    /// JDK is used when no JRE is built for current platform
+    async fn install_adoptium_runtime(
+        major_version: u32,
+        runtime_dir: &Path,
+        progress: &ProgressSender,
+    ) -> Result<PathBuf, String> {
+        let release =
+            match JavaRepository::get_adoptium_release(major_version, ImageType::Jre).await? {
+                Some(release) => release,
+                None => JavaRepository::get_adoptium_release(major_version, ImageType::Jdk)
+                    .await?
+                    .ok_or_else(|| {
+                        format!(
+                            "No Java {} runtime available for this platform",
+                            major_version
+                        )
+                    })?,
+            };
+
+        // Remove leftovers of a broken install
+        let _ = fs::remove_dir_all(runtime_dir);
+
+        JavaRepository::download_package(&release.binary.package, runtime_dir, progress).await?;
+
+        fs::write(runtime_dir.join(VERSION_MARKER), &release.release_name)
+            .map_err(|e| e.to_string())?;
+
+        let java = Self::find_java(runtime_dir)?;
+        Self::set_executable(&java)?;
+
+        Ok(java)
+    }
+
+    fn find_java(runtime_dir: &Path) -> Result<PathBuf, String> {
+        JavaRepository::find_executable(runtime_dir).ok_or_else(|| {
+            format!(
+                "Java executable not found in {}",
+                runtime_dir.to_string_lossy()
+            )
+        })
+    }
+
+    #[cfg(unix)]
+    fn create_link(target: &str, path: &Path) -> Result<(), String> {
+        if fs::symlink_metadata(path).is_ok() {
+            fs::remove_file(path).map_err(|e| e.to_string())?;
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+
+        std::os::unix::fs::symlink(target, path).map_err(|e| e.to_string())
+    }
+
+    /// Windows runtimes don't contain links
+    #[cfg(not(unix))]
+    fn create_link(_target: &str, _path: &Path) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Grant file permissions for Linux, MacOS (Unix Like)
+    fn set_executable(path: &Path) -> Result<(), String> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mut perms = fs::metadata(path).map_err(|e| e.to_string())?.permissions();
+            perms.set_mode(0o755);
+            fs::set_permissions(path, perms).map_err(|e| e.to_string())?;
+        }
+
+        #[cfg(not(unix))]
+        let _ = path;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::module::launcher::model::{Argument, VersionDetail};
+use crate::module::launcher::rule::RuleEvaluator;
+
+/// Holds every `${...}` variable and the rule evaluator used while building
+/// the command line for a single launch
+#[derive(Debug)]
+pub struct LaunchContext {
+    variables: HashMap<String, String>,
+    rules: RuleEvaluator,
+}
+
+impl LaunchContext {
+    pub fn new(rules: RuleEvaluator) -> Self {
+        Self {
+            variables: HashMap::new(),
+            rules,
+        }
+    }
+
+    /// Set value for `${key}`
+    pub fn set(&mut self, key: &str, value: impl Into<String>) -> &mut Self {
+        self.variables.insert(key.to_owned(), value.into());
+        self
+    }
+
+    /// Replace every known `${...}` placeholder in input
+    /// Unknown placeholders are kept as they are
+    pub fn substitute(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len());
+        let mut rest = input;
+
+        while let Some(start) = rest.find("${") {
+            output.push_str(&rest[..start]);
+            let after = &rest[start + 2..];
+
+            let Some(end) = after.find('}') else {
+                output.push_str(&rest[start..]);
+                return output;
+            };
+
+            let key = &after[..end];
+            match self.variables.get(key) {
+                Some(value) => output.push_str(value),
+                None => output.push_str(&rest[start..start + 2 + end + 1]),
+            }
+            rest = &after[end + 1..];
+        }
+
+        output.push_str(rest);
+        output
+    }
+
+    /// Build JVM arguments from version detail
+    /// Versions without `arguments.jvm` get the arguments the official launcher uses
+    pub fn jvm_arguments(&self, detail: &VersionDetail) -> Vec<String> {
+        match &detail.arguments {
+            Some(arguments) if !arguments.jvm.is_empty() => self.resolve(&arguments.jvm),
+            _ => {
+                let mut args = Vec::new();
+
+                let os_name = self.rules.platform().os_name.as_str();
+
+                if os_name == "osx" {
+                    args.push("-XstartOnFirstThread".to_owned());
+                }
+
+                if os_name == "windows" {
+                    args.push("-XX:HeapDumpPath=MojangTricksIntelDriversForPerformance_javaw.exe_minecraft.exe.heapdump".to_owned());
+                }
+
+                args.push("-Djava.library.path=${natives_directory}".to_owned());
+                args.push("-cp".to_owned());
+                args.push("${classpath}".to_owned());
+
+                args.iter().map(|arg| self.substitute(arg)).collect()
+            }
+        }
+    }
+
+    /// Build game arguments from version detail
+    /// Supports both `arguments.game` and legacy `minecraftArguments`
+    pub fn game_arguments(&self, detail: &VersionDetail) -> Vec<String> {
+        if let Some(arguments) = &detail.arguments
+            && !arguments.game.is_empty()
+        {
+            return self.resolve(&arguments.game);
+        }
+
+        if let Some(legacy) = &detail.minecraft_arguments {
+            return legacy
+                .split_whitespace()
+                .map(|arg| self.substitute(arg))
+                .collect();
+        }
+
+        Vec::new()
+    }
+
+    fn resolve(&self, arguments: &[Argument]) -> Vec<String> {
+        let mut output = Vec::new();
+
+        for argument in arguments {
+            match argument {
+                Argument::Plain(value) => output.push(self.substitute(value)),
+                Argument::Conditional { rules, value } => {
+                    if self.rules.is_allowed(rules) {
+                        output.extend(value.values().iter().map(|v| self.substitute(v)));
+                    }
+                }
+            }
+        }
+
+        output
+    }
+}
+
+/// Split user provided arguments on whitespace
+/// Single or double quotes keep spaces inside an argument (eg. `-Dname="A B"`)
+pub fn split_arguments(input: &str) -> Vec<String> {
+    let mut arguments = Vec::new();
+    let mut current = String::new();
+    let mut in_argument = false;
+    let mut quote: Option<char> = None;
+
+    for c in input.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_argument = true;
+            }
+            None if c.is_whitespace() => {
+                if in_argument {
+                    arguments.push(std::mem::take(&mut current));
+                    in_argument = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_argument = true;
+            }
+        }
+    }
+
+    if in_argument {
+        arguments.push(current);
+    }
+
+    arguments
+}
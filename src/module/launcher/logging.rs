@@ -0,0 +1,64 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// Argument used when the version JSON doesn't declare one
+pub const DEFAULT_LOGGING_ARGUMENT: &str = "-Dlog4j.configurationFile=${path}";
+
+/// Disables message lookups, only understood by log4j 2.10 and newer
+pub const NO_LOOKUPS_ARGUMENT: &str = "-Dlog4j2.formatMsgNoLookups=true";
+
+/// First snapshot shipping log4j (13w38a)
+const LOG4J_SINCE: &str = "2013-09-19";
+/// Release date of 1.17
+const NO_LOOKUPS_SINCE: &str = "2021-06-08";
+/// Release date of 1.18.1, first version with patched log4j
+const PATCHED_SINCE: &str = "2021-12-10";
+
+/// `pattern` attribute of a PatternLayout
+static PATTERN_ATTRIBUTE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"pattern="([^"]*)""#).unwrap());
+
+/// Message converter of a pattern, eg. `%msg` or `%m{nolookups}`
+static MESSAGE_CONVERTER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"%(?:msg|message|m)\b(?:\{nolookups\})?").unwrap());
+
+/// Log4Shell (CVE-2021-44228) mitigation for a version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Log4jPatch {
+    /// Version is not affected
+    None,
+    /// Turn lookups off in the message patterns of the client configuration
+    /// Only PatternLayout formats lookups, the XMLLayout console output is kept
+    Config,
+    /// Add `-Dlog4j2.formatMsgNoLookups=true`
+    NoLookups,
+}
+
+impl Log4jPatch {
+    /// Pick mitigation from `releaseTime` of the base game (eg. 2016-06-23T09:17:32+00:00)
+    pub fn for_release(release_time: &str) -> Self {
+        let date = release_time.get(..10).unwrap_or(release_time);
+
+        if !(LOG4J_SINCE..PATCHED_SINCE).contains(&date) {
+            Self::None
+        } else if date < NO_LOOKUPS_SINCE {
+            Self::Config
+        } else {
+            Self::NoLookups
+        }
+    }
+}
+
+/// Replace message converters of every pattern by `%msg{nolookups}`
+pub fn patch_config(config: &str) -> String {
+    PATTERN_ATTRIBUTE
+        .replace_all(config, |captures: &regex::Captures| {
+            let pattern = MESSAGE_CONVERTER.replace_all(&captures[1], "%msg{nolookups}");
+            format!(r#"pattern="{}""#, pattern)
+        })
+        .into_owned()
+}
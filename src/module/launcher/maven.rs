@@ -0,0 +1,183 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+/// Repository used when a library doesn't declare its own
+pub const DEFAULT_MAVEN_URL: &str = "https://libraries.minecraft.net/";
+
+/// Forge Maven, legacy Forge versions still point to its former hosts
+const FORGE_MAVEN_URL: &str = "https://maven.minecraftforge.net/";
+const LEGACY_FORGE_MAVEN_URLS: [&str; 3] = [
+    "http://files.minecraftforge.net/maven/",
+    "https://files.minecraftforge.net/maven/",
+    "http://maven.minecraftforge.net/",
+];
+
+/// Rewrite urls on retired Maven hosts to the current one
+pub fn rewrite_maven_url(url: &str) -> String {
+    LEGACY_FORGE_MAVEN_URLS
+        .iter()
+        .find_map(|legacy| url.strip_prefix(legacy))
+        .map(|path| format!("{}{}", FORGE_MAVEN_URL, path))
+        .unwrap_or_else(|| url.to_owned())
+}
+
+/// Maven coordinate in the form `group:artifact:version[:classifier][@extension]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MavenCoordinate {
+    pub group: String,
+    pub artifact: String,
+    pub version: String,
+    pub classifier: Option<String>,
+    pub extension: String,
+}
+
+impl MavenCoordinate {
+    /// Parse a coordinate, returns None when group, artifact or version is missing
+    pub fn parse(name: &str) -> Option<Self> {
+        let (coordinate, extension) = match name.split_once('@') {
+            Some((coordinate, extension)) => (coordinate, extension),
+            None => (name, "jar"),
+        };
+
+        let mut parts = coordinate.split(':');
+        let group = parts.next().filter(|s| !s.is_empty())?;
+        let artifact = parts.next().filter(|s| !s.is_empty())?;
+        let version = parts.next().filter(|s| !s.is_empty())?;
+        let classifier = parts.next().filter(|s| !s.is_empty());
+
+        Some(Self {
+            group: group.to_owned(),
+            artifact: artifact.to_owned(),
+            version: version.to_owned(),
+            classifier: classifier.map(str::to_owned),
+            extension: extension.to_owned(),
+        })
+    }
+
+    /// Same coordinate with another classifier (eg. natives-linux)
+    pub fn with_classifier(&self, classifier: &str) -> Self {
+        Self {
+            classifier: Some(classifier.to_owned()),
+            ..self.clone()
+        }
+    }
+
+    /// Identify the library regardless of its version
+    pub fn key(&self) -> String {
+        match &self.classifier {
+            Some(classifier) => format!("{}:{}:{}", self.group, self.artifact, classifier),
+            None => format!("{}:{}", self.group, self.artifact),
+        }
+    }
+
+    /// Relative path inside a Maven repository
+    /// eg. `net/fabricmc/tiny-mappings-parser/0.3.0/tiny-mappings-parser-0.3.0.jar`
+    pub fn path(&self) -> String {
+        let file_name = match &self.classifier {
+            Some(classifier) => format!(
+                "{}-{}-{}.{}",
+                self.artifact, self.version, classifier, self.extension
+            ),
+            None => format!("{}-{}.{}", self.artifact, self.version, self.extension),
+        };
+
+        format!(
+            "{}/{}/{}/{}",
+            self.group.replace('.', "/"),
+            self.artifact,
+            self.version,
+            file_name
+        )
+    }
+
+    /// Full download url of the artifact in `repository`
+    pub fn url(&self, repository: &str) -> String {
+        let repository = rewrite_maven_url(repository);
+        format!("{}/{}", repository.trim_end_matches('/'), self.path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_coordinate() {
+        let coordinate = MavenCoordinate::parse("net.fabricmc:fabric-loader:0.15.0").unwrap();
+
+        assert_eq!(coordinate.group, "net.fabricmc");
+        assert_eq!(coordinate.artifact, "fabric-loader");
+        assert_eq!(coordinate.version, "0.15.0");
+        assert_eq!(coordinate.classifier, None);
+        assert_eq!(coordinate.extension, "jar");
+    }
+
+    #[test]
+    fn parse_classifier_and_extension() {
+        let coordinate =
+            MavenCoordinate::parse("de.oceanlabs.mcp:mcp_config:1.20.1-20230612.114412@zip")
+                .unwrap();
+        assert_eq!(coordinate.classifier, None);
+        assert_eq!(coordinate.extension, "zip");
+
+        let coordinate =
+            MavenCoordinate::parse("net.minecraft:client:1.20.1-20230612.114412:mappings@txt")
+                .unwrap();
+        assert_eq!(coordinate.classifier.as_deref(), Some("mappings"));
+        assert_eq!(coordinate.extension, "txt");
+    }
+
+    #[test]
+    fn parse_rejects_incomplete_coordinates() {
+        assert_eq!(MavenCoordinate::parse("net.fabricmc:fabric-loader"), None);
+        assert_eq!(MavenCoordinate::parse("net.fabricmc::0.15.0"), None);
+        assert_eq!(MavenCoordinate::parse(""), None);
+    }
+
+    #[test]
+    fn path_of_coordinate() {
+        let path = |name: &str| MavenCoordinate::parse(name).unwrap().path();
+
+        assert_eq!(
+            path("net.fabricmc:tiny-mappings-parser:0.3.0"),
+            "net/fabricmc/tiny-mappings-parser/0.3.0/tiny-mappings-parser-0.3.0.jar"
+        );
+        assert_eq!(
+            path("org.lwjgl:lwjgl:3.3.1:natives-linux"),
+            "org/lwjgl/lwjgl/3.3.1/lwjgl-3.3.1-natives-linux.jar"
+        );
+        assert_eq!(
+            path("net.minecraftforge:forge:1.20.1-47.2.0:installer"),
+            "net/minecraftforge/forge/1.20.1-47.2.0/forge-1.20.1-47.2.0-installer.jar"
+        );
+        assert_eq!(
+            path("de.oceanlabs.mcp:mcp_config:1.20.1@zip"),
+            "de/oceanlabs/mcp/mcp_config/1.20.1/mcp_config-1.20.1.zip"
+        );
+    }
+
+    #[test]
+    fn key_ignores_version() {
+        let key = |name: &str| MavenCoordinate::parse(name).unwrap().key();
+
+        assert_eq!(key("org.ow2.asm:asm:9.5"), key("org.ow2.asm:asm:9.6"));
+        assert_eq!(
+            key("org.lwjgl:lwjgl:3.3.1:natives-linux"),
+            "org.lwjgl:lwjgl:natives-linux"
+        );
+    }
+
+    #[test]
+    fn url_in_repository() {
+        let coordinate = MavenCoordinate::parse("net.fabricmc:intermediary:1.20.1").unwrap();
+
+        assert_eq!(
+            coordinate.url("https://maven.fabricmc.net/"),
+            "https://maven.fabricmc.net/net/fabricmc/intermediary/1.20.1/intermediary-1.20.1.jar"
+        );
+        assert_eq!(
+            coordinate.url("https://maven.fabricmc.net"),
+            "https://maven.fabricmc.net/net/fabricmc/intermediary/1.20.1/intermediary-1.20.1.jar"
+        );
+    }
+}
@@ -1,6 +1,10 @@
 // Copyright 2026 MineHighVN, AXLauncher contributors
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod argument;
+pub mod logging;
+pub mod maven;
 pub mod model;
 pub mod repository;
+pub mod rule;
 pub mod service;
@@ -1,65 +1,268 @@
 // Copyright 2026 MineHighVN, AXLauncher contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
+use crate::module::launcher::maven::{DEFAULT_MAVEN_URL, MavenCoordinate, rewrite_maven_url};
+use crate::module::launcher::rule::Platform;
+
 #[derive(Deserialize, Debug)]
 #[allow(non_snake_case)]
 pub struct VersionDetail {
+    #[serde(default)]
+    pub id: String,
+    #[serde(rename = "type")]
+    pub version_type: Option<String>,
+    #[serde(rename = "releaseTime")]
+    pub release_time: Option<String>,
+    #[serde(default)]
     pub mainClass: String,
+    pub arguments: Option<Arguments>,
+    /// Pre-1.13 versions store game arguments as a single space separated string
+    #[serde(rename = "minecraftArguments")]
+    pub minecraft_arguments: Option<String>,
     pub downloads: Option<Downloads>,
     pub assetIndex: Option<AssetIndex>,
+    #[serde(default)]
     pub libraries: Vec<Library>,
     #[serde(rename = "inheritsFrom")]
     pub inherits_from: Option<String>,
+    /// Version whose client jar is used, defaults to the version itself
+    pub jar: Option<String>,
+    pub logging: Option<Logging>,
+    #[serde(rename = "javaVersion")]
+    pub java_version: Option<JavaVersion>,
+}
+
+/// Java runtime required by a version
+#[derive(Deserialize, Debug, Clone)]
+pub struct JavaVersion {
+    /// Mojang runtime component (eg. java-runtime-gamma, jre-legacy)
+    pub component: String,
+    #[serde(rename = "majorVersion")]
+    pub major_version: u32,
+}
+
+impl Default for JavaVersion {
+    /// Versions older than 1.17 don't declare a runtime and run on Java 8
+    fn default() -> Self {
+        Self {
+            component: String::from("jre-legacy"),
+            major_version: 8,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Logging {
+    pub client: Option<LoggingConfig>,
+}
+
+/// log4j configuration passed to the game
+#[derive(Deserialize, Debug)]
+pub struct LoggingConfig {
+    /// JVM argument with `${path}` placeholder (eg. -Dlog4j.configurationFile=${path})
+    pub argument: String,
+    pub file: LoggingFile,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LoggingFile {
+    pub id: String,
+    pub url: String,
+    pub sha1: Option<String>,
+    pub size: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct Arguments {
+    #[serde(default)]
+    pub game: Vec<Argument>,
+    #[serde(default)]
+    pub jvm: Vec<Argument>,
+}
+
+/// An argument is either a plain string or a value guarded by rules
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum Argument {
+    Plain(String),
+    Conditional {
+        rules: Vec<Rule>,
+        value: ArgumentValue,
+    },
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum ArgumentValue {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl ArgumentValue {
+    pub fn values(&self) -> Vec<&str> {
+        match self {
+            ArgumentValue::Single(value) => vec![value.as_str()],
+            ArgumentValue::Multiple(values) => values.iter().map(String::as_str).collect(),
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
 pub struct Downloads {
     pub client: DownloadInfo,
+    /// ProGuard mappings of the client jar, published since 1.14.4
+    pub client_mappings: Option<DownloadInfo>,
 }
 
 #[allow(dead_code)]
 #[derive(Deserialize, Debug)]
 pub struct DownloadInfo {
     pub url: String,
+    pub sha1: Option<String>,
     pub size: u64,
 }
 
-#[allow(dead_code)]
 #[derive(Deserialize, Debug)]
 pub struct AssetIndex {
     pub id: String,
     pub url: String,
+    pub sha1: Option<String>,
+    pub size: Option<u64>,
+}
+
+impl AssetIndex {
+    /// Id used as a file name under `assets/indexes` and `assets/virtual`
+    /// Fails when it could point outside those directories, eg. in a crafted version JSON
+    pub fn file_name(&self) -> Result<&str, String> {
+        if self.id.is_empty() || self.id.contains("..") || self.id.contains(['/', '\\']) {
+            return Err(format!("Invalid asset index id: {:?}", self.id));
+        }
+
+        Ok(&self.id)
+    }
 }
 
-#[allow(dead_code)]
 #[derive(Deserialize, Debug)]
 pub struct Library {
+    /// Maven repository of the library, used when `downloads` is missing
     pub url: Option<String>,
     pub downloads: Option<LibDownloads>,
     pub name: String,
     pub rules: Option<Vec<Rule>>,
+    /// Maps OS name to native classifier (eg. "natives-windows-${arch}")
+    pub natives: Option<HashMap<String, String>>,
+    pub extract: Option<ExtractRules>,
+    /// Checksum of Maven resolved artifact, given by Fabric and Quilt profiles
+    pub sha1: Option<String>,
+    pub size: Option<u64>,
+}
+
+impl Library {
+    pub fn coordinate(&self) -> Option<MavenCoordinate> {
+        MavenCoordinate::parse(&self.name)
+    }
+
+    /// Returns the main artifact of library
+    /// Libraries without `downloads` are resolved from their Maven repository
+    pub fn artifact(&self) -> Option<LibArtifact> {
+        if let Some(downloads) = &self.downloads {
+            return downloads.artifact.clone().map(|artifact| LibArtifact {
+                url: rewrite_maven_url(&artifact.url),
+                ..artifact
+            });
+        }
+
+        // Natives only library, its jar only exists with a classifier
+        if self.natives.is_some() {
+            return None;
+        }
+
+        // Checksum is only known for the main artifact
+        self.coordinate().map(|coordinate| LibArtifact {
+            sha1: self.sha1.clone(),
+            size: self.size,
+            ..self.maven_artifact(&coordinate)
+        })
+    }
+
+    /// Returns native artifact of library for the platform
+    pub fn native_artifact(&self, platform: &Platform) -> Option<LibArtifact> {
+        let classifier = self.native_classifier(platform)?;
+
+        if let Some(downloads) = &self.downloads {
+            return downloads.classifiers.as_ref()?.get(&classifier).cloned();
+        }
+
+        let coordinate = self.coordinate()?.with_classifier(&classifier);
+        Some(self.maven_artifact(&coordinate))
+    }
+
+    /// Returns native classifier for the platform, `${arch}` is replaced by 32 or 64
+    pub fn native_classifier(&self, platform: &Platform) -> Option<String> {
+        self.natives
+            .as_ref()?
+            .get(&platform.os_name)
+            .map(|classifier| classifier.replace("${arch}", platform.bitness()))
+    }
+
+    fn maven_artifact(&self, coordinate: &MavenCoordinate) -> LibArtifact {
+        let repository = self
+            .url
+            .as_deref()
+            .filter(|url| !url.is_empty())
+            .unwrap_or(DEFAULT_MAVEN_URL);
+
+        LibArtifact {
+            path: coordinate.path(),
+            url: coordinate.url(repository),
+            sha1: None,
+            size: None,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
 pub struct LibDownloads {
     pub artifact: Option<LibArtifact>,
+    pub classifiers: Option<HashMap<String, LibArtifact>>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Default)]
+pub struct ExtractRules {
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
 pub struct LibArtifact {
     pub path: String,
     pub url: String,
+    pub sha1: Option<String>,
+    pub size: Option<u64>,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct Rule {
-    pub action: String,
+    pub action: RuleAction,
     pub os: Option<OsRule>,
+    pub features: Option<HashMap<String, bool>>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleAction {
+    Allow,
+    Disallow,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct OsRule {
-    pub name: String,
+    pub name: Option<String>,
+    /// Regex matched against the OS version (eg. "^10\\.")
+    pub version: Option<String>,
+    pub arch: Option<String>,
 }
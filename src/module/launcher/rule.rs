@@ -0,0 +1,262 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::module::launcher::model::{Rule, RuleAction};
+
+/// Description of the machine that runs the game
+/// Names follow Mojang's version JSON (osx, windows, linux)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Platform {
+    pub os_name: String,
+    pub os_version: String,
+    pub arch: String,
+}
+
+impl Platform {
+    pub fn new(os_name: &str, os_version: &str, arch: &str) -> Self {
+        Self {
+            os_name: os_name.to_owned(),
+            os_version: os_version.to_owned(),
+            arch: arch.to_owned(),
+        }
+    }
+
+    /// Detect platform of current machine
+    pub fn current() -> Self {
+        let os_name = match std::env::consts::OS {
+            "macos" => "osx",
+            os => os,
+        };
+
+        Self::new(os_name, &Self::detect_os_version(), std::env::consts::ARCH)
+    }
+
+    /// Returns "32" or "64", used by `${arch}` in native classifiers
+    pub fn bitness(&self) -> &'static str {
+        match self.arch.as_str() {
+            "x86" | "arm" => "32",
+            _ => "64",
+        }
+    }
+
+    fn detect_os_version() -> String {
+        #[cfg(target_os = "linux")]
+        let version = std::fs::read_to_string("/proc/sys/kernel/osrelease").ok();
+
+        #[cfg(target_os = "macos")]
+        let version = std::process::Command::new("sw_vers")
+            .arg("-productVersion")
+            .output()
+            .ok()
+            .map(|output| String::from_utf8_lossy(&output.stdout).into_owned());
+
+        // Output looks like "Microsoft Windows [Version 10.0.19045.3803]"
+        #[cfg(target_os = "windows")]
+        let version = std::process::Command::new("cmd")
+            .args(["/C", "ver"])
+            .output()
+            .ok()
+            .and_then(|output| {
+                let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+                let start = stdout.find("Version ")? + "Version ".len();
+                let end = stdout[start..].find(']')? + start;
+                Some(stdout[start..end].to_owned())
+            });
+
+        #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+        let version: Option<String> = None;
+
+        version.map(|v| v.trim().to_owned()).unwrap_or_default()
+    }
+}
+
+/// Evaluates Mojang rules against a platform and a set of launcher features
+/// Used for libraries, arguments and natives
+#[derive(Debug, Clone)]
+pub struct RuleEvaluator {
+    platform: Platform,
+    features: HashMap<String, bool>,
+}
+
+impl Default for RuleEvaluator {
+    fn default() -> Self {
+        Self::new(Platform::current())
+    }
+}
+
+impl RuleEvaluator {
+    pub fn new(platform: Platform) -> Self {
+        Self {
+            platform,
+            features: HashMap::new(),
+        }
+    }
+
+    pub fn platform(&self) -> &Platform {
+        &self.platform
+    }
+
+    /// Enable or disable a launcher feature (eg. has_custom_resolution)
+    pub fn set_feature(&mut self, key: &str, enabled: bool) {
+        self.features.insert(key.to_owned(), enabled);
+    }
+
+    /// Empty rules allow everything
+    /// Otherwise start from disallow and let the last matching rule decide
+    pub fn is_allowed(&self, rules: &[Rule]) -> bool {
+        if rules.is_empty() {
+            return true;
+        }
+
+        let mut allowed = false;
+
+        for rule in rules {
+            if self.matches(rule) {
+                allowed = rule.action == RuleAction::Allow;
+            }
+        }
+
+        allowed
+    }
+
+    fn matches(&self, rule: &Rule) -> bool {
+        if let Some(os) = &rule.os {
+            if let Some(name) = &os.name
+                && name != &self.platform.os_name
+            {
+                return false;
+            }
+
+            if let Some(arch) = &os.arch
+                && arch != &self.platform.arch
+            {
+                return false;
+            }
+
+            if let Some(version) = &os.version {
+                let Ok(pattern) = Regex::new(version) else {
+                    return false;
+                };
+
+                if !pattern.is_match(&self.platform.os_version) {
+                    return false;
+                }
+            }
+        }
+
+        if let Some(features) = &rule.features {
+            return features.iter().all(|(key, expected)| {
+                self.features.get(key).copied().unwrap_or(false) == *expected
+            });
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(json: &str) -> Vec<Rule> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn windows() -> RuleEvaluator {
+        RuleEvaluator::new(Platform::new("windows", "10.0.19045", "x86_64"))
+    }
+
+    fn osx() -> RuleEvaluator {
+        RuleEvaluator::new(Platform::new("osx", "14.2.1", "aarch64"))
+    }
+
+    fn linux() -> RuleEvaluator {
+        RuleEvaluator::new(Platform::new("linux", "6.8.0-40-generic", "x86_64"))
+    }
+
+    #[test]
+    fn empty_rules_allow_everything() {
+        assert!(linux().is_allowed(&[]));
+    }
+
+    #[test]
+    fn allow_only_on_os() {
+        let rules = rules(r#"[{ "action": "allow", "os": { "name": "osx" } }]"#);
+
+        assert!(osx().is_allowed(&rules));
+        assert!(!windows().is_allowed(&rules));
+        assert!(!linux().is_allowed(&rules));
+    }
+
+    #[test]
+    fn last_matching_rule_wins() {
+        // LWJGL 2 libraries are used everywhere except on macOS
+        let rules = rules(
+            r#"[
+                { "action": "allow" },
+                { "action": "disallow", "os": { "name": "osx" } }
+            ]"#,
+        );
+
+        assert!(windows().is_allowed(&rules));
+        assert!(linux().is_allowed(&rules));
+        assert!(!osx().is_allowed(&rules));
+    }
+
+    #[test]
+    fn os_version_is_a_regex() {
+        let rules =
+            rules(r#"[{ "action": "allow", "os": { "name": "windows", "version": "^10\\." } }]"#);
+
+        assert!(windows().is_allowed(&rules));
+        let windows_7 = RuleEvaluator::new(Platform::new("windows", "6.1.7601", "x86_64"));
+        assert!(!windows_7.is_allowed(&rules));
+    }
+
+    #[test]
+    fn invalid_os_version_regex_never_matches() {
+        let rules = rules(r#"[{ "action": "allow", "os": { "version": "(" } }]"#);
+
+        assert!(!linux().is_allowed(&rules));
+    }
+
+    #[test]
+    fn os_arch() {
+        let rules = rules(r#"[{ "action": "allow", "os": { "arch": "x86" } }]"#);
+
+        assert!(!windows().is_allowed(&rules));
+        let windows_32 = RuleEvaluator::new(Platform::new("windows", "10.0.19045", "x86"));
+        assert!(windows_32.is_allowed(&rules));
+    }
+
+    #[test]
+    fn features_must_all_match() {
+        let rules = rules(
+            r#"[{
+                "action": "allow",
+                "features": { "has_custom_resolution": true, "is_demo_user": false }
+            }]"#,
+        );
+
+        let mut evaluator = linux();
+        assert!(!evaluator.is_allowed(&rules));
+
+        evaluator.set_feature("has_custom_resolution", true);
+        assert!(evaluator.is_allowed(&rules));
+
+        evaluator.set_feature("is_demo_user", true);
+        assert!(!evaluator.is_allowed(&rules));
+    }
+
+    #[test]
+    fn bitness_of_arch() {
+        assert_eq!(Platform::new("windows", "", "x86").bitness(), "32");
+        assert_eq!(Platform::new("linux", "", "arm").bitness(), "32");
+        assert_eq!(Platform::new("linux", "", "x86_64").bitness(), "64");
+        assert_eq!(Platform::new("osx", "", "aarch64").bitness(), "64");
+    }
+}
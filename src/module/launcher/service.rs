@@ -1,23 +1,45 @@
 // Copyright 2026 MineHighVN, AXLauncher contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashSet;
 use std::io::Error;
 use std::path::PathBuf;
 
 use std::fs;
 use std::path::Path;
-use std::process::Command;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use tokio::process::Command;
+
+use crate::module::asset::service::AssetService;
+use crate::module::config::model::AppConfig;
 use crate::module::config::repository::ConfigRepository;
-use crate::module::launcher::model::VersionDetail;
+use crate::module::download::entity::DownloadTask;
+use crate::module::download::repository::DownloadRepository;
+use crate::module::download::service::{DownloadService, MAX_CONCURRENT_DOWNLOADS, ProgressSender};
+use crate::module::instance::model::Instance;
+use crate::module::java::service::JavaService;
+use crate::module::launcher::argument::{LaunchContext, split_arguments};
+use crate::module::launcher::logging::{
+    self, DEFAULT_LOGGING_ARGUMENT, Log4jPatch, NO_LOOKUPS_ARGUMENT,
+};
+use crate::module::launcher::model::{LibArtifact, Library, LoggingFile, VersionDetail};
 use crate::module::launcher::repository::LauncherRepository;
+use crate::module::launcher::rule::RuleEvaluator;
+use crate::module::mods::service::ModService;
 use crate::module::mojang::entity::MinecraftVersion;
+use crate::module::mojang::repository::MojangRepository;
+use crate::module::process::repository::ProcessRepository;
 
 struct LauncherPaths {
     root_dir: PathBuf,
+    /// Directory the game runs in (saves, mods, options.txt), root or an instance directory
+    game_dir: PathBuf,
     version_dir: PathBuf,
     libraries_dir: PathBuf,
     assets_dir: PathBuf,
+    natives_dir: PathBuf,
     version_json: PathBuf,
     client_jar: PathBuf,
 }
@@ -26,37 +48,83 @@ impl LauncherPaths {
     /// Create new instance and setup paths for Minecraft
     /// Based on current_dir, root will be at {current_dir}/../minecraft_data
     /// Initialize paths for version, jar, libraries and assets
+    /// Natives directory is unique per launch and removed when the game exits
     /// TODO: Add path for release (%APPDATA%/.minecraft, ~/.minecraft) later
     /// TODO: Path is editable, not fixed
     fn new(version_id: &str, minecraft_root_dir: PathBuf) -> Result<Self, String> {
         let root_dir = minecraft_root_dir;
         let version_dir = root_dir.join("versions").join(version_id);
+        let launch_stamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| e.to_string())?
+            .as_millis();
 
         Ok(Self {
             version_json: version_dir.join(format!("{}.json", version_id)),
             client_jar: version_dir.join(format!("{}.jar", version_id)),
             libraries_dir: root_dir.join("libraries"),
             assets_dir: root_dir.join("assets"),
+            natives_dir: version_dir.join(format!("natives-{}", launch_stamp)),
             version_dir,
+            game_dir: root_dir.clone(),
             root_dir,
         })
     }
 
+    /// Use client jar of another version (eg. parent of a modded version)
+    fn use_client_jar(&mut self, jar_id: &str) {
+        self.client_jar = self
+            .root_dir
+            .join("versions")
+            .join(jar_id)
+            .join(format!("{}.jar", jar_id));
+    }
+
     /// Ensure all dependency directories are exists
     /// Missing directories will be created
     fn ensure_directories(&self) -> Result<(), String> {
         fs::create_dir_all(&self.version_dir).map_err(|e| e.to_string())?;
         fs::create_dir_all(&self.libraries_dir).map_err(|e| e.to_string())?;
         fs::create_dir_all(&self.assets_dir).map_err(|e| e.to_string())?;
+        fs::create_dir_all(&self.game_dir).map_err(|e| e.to_string())?;
         Ok(())
     }
 }
 
+/// Removes the natives directory of a launch unless the game was started
+struct NativesGuard {
+    dir: Option<PathBuf>,
+}
+
+impl NativesGuard {
+    fn new(dir: &Path) -> Self {
+        Self {
+            dir: Some(dir.to_path_buf()),
+        }
+    }
+
+    /// Keep the directory, the running game removes it once it exits
+    fn disarm(mut self) {
+        self.dir = None;
+    }
+}
+
+impl Drop for NativesGuard {
+    fn drop(&mut self) {
+        if let Some(dir) = &self.dir {
+            let _ = fs::remove_dir_all(dir);
+        }
+    }
+}
+
 // This struct defines arguments for launching minecraft
 pub struct LaunchArgs {
     pub username: String,
     pub uuid: String,
     pub access_token: String,
+    pub user_type: String,
+    pub client_id: String,
+    pub xuid: String,
 }
 
 impl Default for LaunchArgs {
@@ -66,6 +134,9 @@ impl Default for LaunchArgs {
             username: "".to_owned(),
             uuid: "00000000-0000-0000-0000-000000000000".to_owned(),
             access_token: "0".to_owned(),
+            user_type: "legacy".to_owned(),
+            client_id: "".to_owned(),
+            xuid: "".to_owned(),
         }
     }
 }
@@ -73,37 +144,175 @@ impl Default for LaunchArgs {
 pub struct LauncherService {}
 
 impl LauncherService {
-    fn get_minecraft_root_dir() -> Result<PathBuf, Error> {
+    pub fn get_minecraft_root_dir() -> Result<PathBuf, Error> {
         let config = ConfigRepository::load();
 
         let minecraft_root_dir = config.minecraft_root_dir;
 
-        return Ok(minecraft_root_dir.into());
+        Ok(minecraft_root_dir.into())
     }
 
     /// Lauch minecraft
-    pub async fn launch(launch_args: LaunchArgs, version: MinecraftVersion) -> Result<(), String> {
+    /// Without an instance the game runs in the Minecraft root directory
+    /// Download progress is published to `progress`, the game process is registered in `processes`
+    /// under the instance id, or the version id when no instance is used
+    pub async fn launch(
+        launch_args: LaunchArgs,
+        version: MinecraftVersion,
+        instance: Option<Instance>,
+        skip_mod_check: bool,
+        progress: ProgressSender,
+        processes: Arc<ProcessRepository>,
+    ) -> Result<(), String> {
+        let process_id = instance
+            .as_ref()
+            .map(|instance| instance.id.clone())
+            .unwrap_or_else(|| version.id.clone());
+
+        // Only one game can run for each version or instance
+        processes.reserve(&process_id)?;
+
+        let result = Self::launch_reserved(
+            launch_args,
+            &version,
+            instance.as_ref(),
+            skip_mod_check,
+            &process_id,
+            &progress,
+            &processes,
+        )
+        .await;
+        if result.is_err() {
+            processes.release(&process_id);
+        }
+
+        result
+    }
+
+    async fn launch_reserved(
+        launch_args: LaunchArgs,
+        version: &MinecraftVersion,
+        instance: Option<&Instance>,
+        skip_mod_check: bool,
+        process_id: &str,
+        progress: &ProgressSender,
+        processes: &ProcessRepository,
+    ) -> Result<(), String> {
+        // Broken mod sets only crash on the loading screen, report them before starting Java
+        if let Some(instance) = instance
+            && !skip_mod_check
+        {
+            ModService::ensure_loadable(instance).await?;
+        }
+
         let minecraft_root_dir = Self::get_minecraft_root_dir().map_err(|e| e.to_string())?;
 
         // Create path
-        let paths = LauncherPaths::new(&version.id, minecraft_root_dir)?;
+        let mut paths = LauncherPaths::new(&version.id, minecraft_root_dir)?;
+        if let Some(instance) = instance {
+            paths.game_dir = instance.path.clone();
+        }
         paths.ensure_directories()?;
 
         // Install Metadata
-        let detail = Self::prepare_version_metadata(&version, &paths).await?;
+        let detail = Self::prepare_version_metadata(version, &paths).await?;
+        if let Some(jar) = &detail.jar {
+            paths.use_client_jar(jar);
+        }
+
+        // Instance settings replace global ones
+        let mut config = ConfigRepository::load();
+        let resolution = instance.and_then(|instance| instance.overrides.resolution());
+        if let Some(instance) = instance {
+            instance.overrides.apply(&mut config);
+        }
 
         // Install all libraries (eg. jar, libraries)
-        Self::prepare_dependencies(&detail, &paths).await?;
+        let full_check = config.verify_file_hashes;
+        let mut rules = RuleEvaluator::default();
+        rules.set_feature("has_custom_resolution", resolution.is_some());
+        Self::prepare_dependencies(&detail, &paths, &rules, full_check, progress).await?;
+
+        // Install asset index and objects (eg. sounds, languages)
+        let game_assets = match &detail.assetIndex {
+            Some(asset_index) => {
+                AssetService::prepare(
+                    asset_index,
+                    &paths.assets_dir,
+                    &paths.game_dir,
+                    full_check,
+                    progress,
+                )
+                .await?
+            }
+            None => paths.assets_dir.clone(),
+        };
+
+        // Install log4j configuration
+        let logging_arguments = Self::prepare_logging(&detail, &paths, full_check).await?;
+
+        // Extract native libraries (eg. LWJGL .so, .dll, .dylib)
+        let natives_guard = NativesGuard::new(&paths.natives_dir);
+        Self::extract_natives(&detail, &paths, &rules)?;
 
         // Build classpath
-        let classpath = Self::build_classpath(&detail, &paths);
+        let classpath = Self::build_classpath(&detail, &paths, &rules);
 
         // Find java
-        let current_dir = std::env::current_dir().map_err(|e| e.to_string())?;
-        let java_path = Self::get_java_path(&current_dir.join("..")).await?;
+        let java_path = Self::get_java_path(
+            &detail,
+            &config.java_path,
+            &JavaService::runtime_root()?,
+            full_check,
+            progress,
+        )
+        .await?;
+
+        let mut context = Self::build_launch_context(
+            &paths,
+            &detail,
+            rules,
+            classpath,
+            &game_assets,
+            &version.id,
+            &launch_args,
+        );
+        if let Some((width, height)) = resolution {
+            context
+                .set("resolution_width", width.to_string())
+                .set("resolution_height", height.to_string());
+        }
 
         // Run minecraft
-        Self::run_minecraft(java_path, paths, detail, classpath, version.id, launch_args)
+        let mut cmd = Self::build_command(
+            java_path,
+            &paths,
+            &detail,
+            &context,
+            logging_arguments,
+            &config,
+        );
+
+        // Legacy minecraftArguments have no resolution placeholders
+        if let Some((width, height)) = resolution
+            && detail.arguments.is_none()
+        {
+            cmd.args([
+                "--width".to_owned(),
+                width.to_string(),
+                "--height".to_owned(),
+                height.to_string(),
+            ]);
+        }
+
+        // Natives are only needed while the game is running
+        let natives_dir = paths.natives_dir;
+        processes.spawn(process_id, cmd, move || {
+            let _ = fs::remove_dir_all(natives_dir);
+        })?;
+        natives_guard.disarm();
+
+        Ok(())
     }
 
     /// Prepare version metadata
@@ -118,190 +327,498 @@ impl LauncherService {
             };
 
             if !paths.version_json.exists() {
-                LauncherRepository::download_file(&version_url, &paths.version_json).await?;
+                DownloadRepository::download_file(version_url, &paths.version_json, None, None)
+                    .await?;
             }
         }
 
-        let content = fs::read_to_string(&paths.version_json).map_err(|e| e.to_string())?;
-        let mut detail: VersionDetail =
-            serde_json::from_str(&content).map_err(|e| e.to_string())?;
-
-        // Handle version inherits
-        if let Some(parent_id) = &detail.inherits_from {
-            let root_dir = paths.root_dir.clone();
-            let parent_json_path = root_dir
-                .join("versions")
-                .join(parent_id)
-                .join(format!("{}.json", parent_id));
-
-            if parent_json_path.exists() {
-                let parent_content =
-                    fs::read_to_string(parent_json_path).map_err(|e| e.to_string())?;
-                let parent_detail: VersionDetail =
-                    serde_json::from_str(&parent_content).map_err(|e| e.to_string())?;
-
-                // Combind libraries
-                let mut all_libraries = detail.libraries;
-                all_libraries.extend(parent_detail.libraries);
-                detail.libraries = all_libraries;
-
-                if detail.downloads.is_none() {
-                    detail.downloads = parent_detail.downloads;
-                }
+        let mut detail = Self::read_version_json(&paths.version_json)?;
+        if detail.id.is_empty() {
+            detail.id = version.id.clone();
+        }
 
-                if detail.assetIndex.is_none() {
-                    detail.assetIndex = parent_detail.assetIndex;
-                }
+        Self::resolve_inheritance(detail, &paths.root_dir).await
+    }
+
+    /// Download client jar of a version into `versions/<id>/<id>.jar`
+    /// Loader installers patch it before the game is launched
+    pub async fn prepare_client_jar(
+        id: &str,
+        root_dir: &Path,
+        full_check: bool,
+        progress: &ProgressSender,
+    ) -> Result<(VersionDetail, PathBuf), String> {
+        let detail = Self::load_version(id, root_dir).await?;
+        let client_jar = root_dir
+            .join("versions")
+            .join(id)
+            .join(format!("{}.jar", id));
+
+        let downloads = detail
+            .downloads
+            .as_ref()
+            .ok_or_else(|| format!("{} has no client jar", id))?;
+        let client = &downloads.client;
+        let sha1 = client.sha1.as_deref();
+
+        if !DownloadRepository::is_valid(&client_jar, sha1, Some(client.size), full_check) {
+            let task = DownloadTask::new(&client.url, client_jar.clone(), sha1, Some(client.size));
+            DownloadService::download_all(vec![task], 1, progress).await?;
+        }
+
+        Ok((detail, client_jar))
+    }
+
+    /// Read an installed version with its whole inheritsFrom chain merged
+    pub async fn load_version(id: &str, root_dir: &Path) -> Result<VersionDetail, String> {
+        let detail = Self::load_parent_version(id, root_dir).await?;
+        Self::resolve_inheritance(detail, root_dir).await
+    }
+
+    /// Resolve the whole inheritsFrom chain, child values win over parent values
+    async fn resolve_inheritance(
+        mut detail: VersionDetail,
+        root_dir: &Path,
+    ) -> Result<VersionDetail, String> {
+        let mut visited = HashSet::from([detail.id.clone()]);
+        while let Some(parent_id) = detail.inherits_from.take() {
+            if !visited.insert(parent_id.clone()) {
+                return Err(format!("Circular inheritsFrom on version {}", parent_id));
             }
+
+            let parent = Self::load_parent_version(&parent_id, root_dir).await?;
+            detail = Self::merge_version(detail, parent);
         }
 
         Ok(detail)
     }
 
+    fn read_version_json(path: &Path) -> Result<VersionDetail, String> {
+        let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Read parent version from `versions/<id>/<id>.json`
+    /// Missing parent is downloaded from the version manifest
+    async fn load_parent_version(id: &str, root_dir: &Path) -> Result<VersionDetail, String> {
+        let json_path = root_dir
+            .join("versions")
+            .join(id)
+            .join(format!("{}.json", id));
+
+        if !json_path.exists() {
+            let versions = MojangRepository::new().get_all_versions().await?;
+            let url = versions
+                .into_iter()
+                .find(|v| v.id == id)
+                .and_then(|v| v.url)
+                .ok_or_else(|| format!("Parent version {} not found", id))?;
+
+            DownloadRepository::download_file(&url, &json_path, None, None).await?;
+        }
+
+        let mut detail = Self::read_version_json(&json_path)?;
+        if detail.id.is_empty() {
+            detail.id = id.to_owned();
+        }
+
+        Ok(detail)
+    }
+
+    /// Merge child version on top of its parent
+    fn merge_version(child: VersionDetail, parent: VersionDetail) -> VersionDetail {
+        // Child libraries override parent ones with the same group:artifact[:classifier]
+        let child_keys: HashSet<String> = child.libraries.iter().map(Self::library_key).collect();
+
+        let mut libraries = child.libraries;
+        libraries.extend(
+            parent
+                .libraries
+                .into_iter()
+                .filter(|lib| !child_keys.contains(&Self::library_key(lib))),
+        );
+
+        // Arguments of child are appended to parent arguments
+        let arguments = match (parent.arguments, child.arguments) {
+            (Some(mut parent_args), Some(child_args)) => {
+                parent_args.game.extend(child_args.game);
+                parent_args.jvm.extend(child_args.jvm);
+                Some(parent_args)
+            }
+            (parent_args, child_args) => child_args.or(parent_args),
+        };
+
+        // Use client jar of the nearest ancestor owning one, parents without
+        // `downloads.client` (eg. Forge under OptiFine) only pass theirs through
+        let jar = match (&child.jar, &child.downloads) {
+            (Some(jar), _) => Some(jar.clone()),
+            (None, Some(_)) => None,
+            (None, None) if parent.downloads.is_some() => parent.jar.or(Some(parent.id)),
+            (None, None) => parent.jar,
+        };
+
+        VersionDetail {
+            id: child.id,
+            version_type: child.version_type.or(parent.version_type),
+            // Release date of the base game, loaders often use their install date
+            release_time: parent.release_time.or(child.release_time),
+            mainClass: if child.mainClass.is_empty() {
+                parent.mainClass
+            } else {
+                child.mainClass
+            },
+            arguments,
+            // LaunchWrapper versions replace parent arguments, tweakers included
+            minecraft_arguments: child.minecraft_arguments.or(parent.minecraft_arguments),
+            downloads: child.downloads.or(parent.downloads),
+            assetIndex: child.assetIndex.or(parent.assetIndex),
+            libraries,
+            inherits_from: parent.inherits_from,
+            jar,
+            logging: child.logging.or(parent.logging),
+            java_version: child.java_version.or(parent.java_version),
+        }
+    }
+
+    /// Identify a library regardless of its version
+    fn library_key(lib: &Library) -> String {
+        lib.coordinate()
+            .map(|coordinate| coordinate.key())
+            .unwrap_or_else(|| lib.name.clone())
+    }
+
     /// Prepare all dependencies
-    /// Missing dependencies will be installed automatically
+    /// Missing or corrupted dependencies will be installed automatically
     async fn prepare_dependencies(
         detail: &VersionDetail,
         paths: &LauncherPaths,
+        rules: &RuleEvaluator,
+        full_check: bool,
+        progress: &ProgressSender,
     ) -> Result<(), String> {
+        let mut tasks = Vec::new();
+
         // Install client jar
-        if paths.client_jar.try_exists().map_err(|e| e.to_string())? == false {
-            if let Some(downloads) = &detail.downloads {
-                LauncherRepository::download_file(&downloads.client.url, &paths.client_jar).await?;
+        if let Some(downloads) = &detail.downloads {
+            let client = &downloads.client;
+            let sha1 = client.sha1.as_deref();
+
+            if !DownloadRepository::is_valid(&paths.client_jar, sha1, Some(client.size), full_check)
+            {
+                tasks.push(DownloadTask::new(
+                    &client.url,
+                    paths.client_jar.clone(),
+                    sha1,
+                    Some(client.size),
+                ));
             }
         }
 
         // Install libraries
-        for lib in &detail.libraries {
-            if !LauncherRepository::should_download_lib(lib) {
+        tasks.extend(Self::library_tasks(
+            &detail.libraries,
+            &paths.libraries_dir,
+            rules,
+            full_check,
+        ));
+
+        DownloadService::download_all(tasks, MAX_CONCURRENT_DOWNLOADS, progress).await
+    }
+
+    /// Returns download tasks for missing or invalid libraries allowed by rules, natives included
+    pub fn library_tasks(
+        libraries: &[Library],
+        libraries_dir: &Path,
+        rules: &RuleEvaluator,
+        full_check: bool,
+    ) -> Vec<DownloadTask> {
+        let mut tasks = Vec::new();
+
+        for lib in libraries {
+            if !LauncherRepository::should_download_lib(lib, rules) {
                 continue;
             }
 
-            if let Some(artifact) = lib.downloads.as_ref().and_then(|d| d.artifact.as_ref()) {
-                let lib_path = paths.libraries_dir.join(&artifact.path);
-                if !lib_path.exists() {
-                    if let Some(parent) = lib_path.parent() {
-                        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
-                    }
-                    LauncherRepository::download_file(&artifact.url, &lib_path).await?;
-                }
+            if let Some(artifact) = lib.artifact() {
+                tasks.extend(Self::artifact_task(&artifact, libraries_dir, full_check));
+            }
+
+            // Install native classifier
+            if let Some(native) = lib.native_artifact(rules.platform()) {
+                tasks.extend(Self::artifact_task(&native, libraries_dir, full_check));
             }
         }
-        Ok(())
+
+        tasks
     }
 
-    // Convert java library name to path
-    fn name_to_path(name: &String) -> String {
-        let parts: Vec<&str> = name.split(':').collect();
-        if parts.len() < 3 {
-            return name.clone();
+    /// Returns download task for library artifact if it is missing or invalid
+    fn artifact_task(
+        artifact: &LibArtifact,
+        libraries_dir: &Path,
+        full_check: bool,
+    ) -> Option<DownloadTask> {
+        let lib_path = libraries_dir.join(&artifact.path);
+        let sha1 = artifact.sha1.as_deref();
+
+        // Artifacts without url are generated locally (eg. by loader installers)
+        if artifact.url.is_empty()
+            || DownloadRepository::is_valid(&lib_path, sha1, artifact.size, full_check)
+        {
+            return None;
         }
 
-        let group = parts[0].replace('.', "/");
-        let artifact = parts[1];
-        let version = parts[2];
+        Some(DownloadTask::new(
+            &artifact.url,
+            lib_path,
+            sha1,
+            artifact.size,
+        ))
+    }
 
-        format!(
-            "{}/{}/{}/{}-{}.jar",
-            group, artifact, version, artifact, version
-        )
+    /// Download log4j configuration into `assets/log_configs`
+    /// Returns JVM arguments enabling it, including Log4Shell mitigation for affected versions
+    async fn prepare_logging(
+        detail: &VersionDetail,
+        paths: &LauncherPaths,
+        full_check: bool,
+    ) -> Result<Vec<String>, String> {
+        let client = detail.logging.as_ref().and_then(|l| l.client.as_ref());
+        let patch = detail
+            .release_time
+            .as_deref()
+            .map(Log4jPatch::for_release)
+            .unwrap_or(Log4jPatch::None);
+
+        let Some(client) = client else {
+            return Ok(match patch {
+                Log4jPatch::NoLookups => vec![NO_LOOKUPS_ARGUMENT.to_owned()],
+                Log4jPatch::Config | Log4jPatch::None => Vec::new(),
+            });
+        };
+
+        let mut path = Self::download_log_config(&client.file, paths, full_check).await?;
+        let mut arguments = Vec::new();
+
+        match patch {
+            Log4jPatch::Config => path = Self::patch_log_config(&path)?,
+            Log4jPatch::NoLookups => arguments.push(NO_LOOKUPS_ARGUMENT.to_owned()),
+            Log4jPatch::None => {}
+        }
+
+        let argument = if client.argument.is_empty() {
+            DEFAULT_LOGGING_ARGUMENT
+        } else {
+            client.argument.as_str()
+        };
+        arguments.push(argument.replace("${path}", &path));
+
+        Ok(arguments)
     }
 
-    /// Build classpath
-    fn build_classpath(detail: &VersionDetail, paths: &LauncherPaths) -> String {
-        let mut entries = vec![paths.client_jar.to_str().unwrap().to_string()];
+    /// Write a copy of a log4j configuration with message lookups disabled
+    /// Returns path of the copy, next to the original
+    fn patch_log_config(path: &str) -> Result<String, String> {
+        let config = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let patched_path = Path::new(path).with_extension("nolookups.xml");
+
+        fs::write(&patched_path, logging::patch_config(&config)).map_err(|e| e.to_string())?;
+
+        Ok(patched_path.to_string_lossy().into_owned())
+    }
+
+    /// Returns local path of log4j configuration, downloading it when missing or invalid
+    async fn download_log_config(
+        file: &LoggingFile,
+        paths: &LauncherPaths,
+        full_check: bool,
+    ) -> Result<String, String> {
+        let config_path = paths.assets_dir.join("log_configs").join(&file.id);
+        let sha1 = file.sha1.as_deref();
+
+        if !DownloadRepository::is_valid(&config_path, sha1, file.size, full_check) {
+            DownloadRepository::download_file(&file.url, &config_path, sha1, file.size).await?;
+        }
+
+        Ok(config_path.to_string_lossy().into_owned())
+    }
+
+    /// Extract every native library into the per-launch natives directory
+    fn extract_natives(
+        detail: &VersionDetail,
+        paths: &LauncherPaths,
+        rules: &RuleEvaluator,
+    ) -> Result<(), String> {
+        fs::create_dir_all(&paths.natives_dir).map_err(|e| e.to_string())?;
 
         for lib in &detail.libraries {
-            if !LauncherRepository::should_download_lib(lib) {
+            if !LauncherRepository::should_download_lib(lib, rules) {
                 continue;
             }
-            let relative_path =
-                if let Some(artifact) = lib.downloads.as_ref().and_then(|d| d.artifact.as_ref()) {
-                    Some(artifact.path.clone())
-                } else {
-                    Some(Self::name_to_path(&lib.name))
-                };
 
-            if let Some(path) = relative_path {
-                let lib_path = paths.libraries_dir.join(path);
-                if let Some(p_str) = lib_path.to_str() {
-                    entries.push(p_str.to_string());
-                }
-            }
+            let Some(native) = lib.native_artifact(rules.platform()) else {
+                continue;
+            };
+
+            let exclude = lib
+                .extract
+                .as_ref()
+                .map(|e| e.exclude.as_slice())
+                .unwrap_or_default();
+
+            LauncherRepository::extract_natives(
+                &paths.libraries_dir.join(&native.path),
+                &paths.natives_dir,
+                exclude,
+            )?;
         }
 
+        Ok(())
+    }
+
+    /// Classpath separator for current OS
+    pub fn classpath_separator() -> &'static str {
         #[cfg(target_os = "windows")]
         let sep = ";";
         #[cfg(not(target_os = "windows"))]
         let sep = ":";
 
-        entries.join(sep)
+        sep
     }
 
-    /// Spawn a new command process to run Minecraft by using Java runtime
-    fn run_minecraft(
-        java: PathBuf,
-        paths: LauncherPaths,
-        detail: VersionDetail,
-        classpath: String,
-        id: String,
-        args: LaunchArgs,
-    ) -> Result<(), String> {
-        let mut cmd = Command::new(java);
-        cmd.current_dir(&paths.root_dir);
+    /// Build classpath
+    fn build_classpath(
+        detail: &VersionDetail,
+        paths: &LauncherPaths,
+        rules: &RuleEvaluator,
+    ) -> String {
+        let mut entries = vec![paths.client_jar.to_str().unwrap().to_string()];
+
+        for lib in &detail.libraries {
+            if !LauncherRepository::should_download_lib(lib, rules) {
+                continue;
+            }
 
-        #[cfg(target_os = "macos")]
-        cmd.arg("-XstartOnFirstThread");
+            // Natives only libraries are extracted instead of being on classpath
+            if let Some(artifact) = lib.artifact() {
+                let lib_path = paths.libraries_dir.join(artifact.path);
+                if let Some(p_str) = lib_path.to_str() {
+                    entries.push(p_str.to_string());
+                }
+            }
+        }
 
+        entries.join(Self::classpath_separator())
+    }
+
+    /// Collect every `${...}` variable used by version arguments
+    fn build_launch_context(
+        paths: &LauncherPaths,
+        detail: &VersionDetail,
+        rules: RuleEvaluator,
+        classpath: String,
+        game_assets: &Path,
+        id: &str,
+        args: &LaunchArgs,
+    ) -> LaunchContext {
         let asset_index_id = detail
             .assetIndex
             .as_ref()
             .map(|a| a.id.as_str())
             .unwrap_or("legacy");
 
-        cmd.args([
-            "-Xmx2G",
-            "-cp",
-            &classpath,
-            &detail.mainClass,
-            "--version",
-            &id,
-            "--gameDir",
-            ".",
-            "--assetsDir",
-            paths.assets_dir.to_str().unwrap(),
-            "--assetIndex",
-            &asset_index_id,
-            "--username",
-            &args.username,
-            "--uuid",
-            &args.uuid,
-            "--accessToken",
-            &args.access_token,
-            "--userType",
-            "legacy",
-        ]);
-
-        cmd.spawn()
-            .map(|_| ())
-            .map_err(|e| format!("Spawn error: {}", e))
+        let path_str = |path: &Path| path.to_string_lossy().into_owned();
+
+        let mut context = LaunchContext::new(rules);
+        context
+            .set("auth_player_name", &args.username)
+            .set("auth_uuid", &args.uuid)
+            .set("auth_access_token", &args.access_token)
+            .set(
+                "auth_session",
+                format!("token:{}:{}", args.access_token, args.uuid),
+            )
+            .set("auth_xuid", &args.xuid)
+            .set("clientid", &args.client_id)
+            .set("user_type", &args.user_type)
+            .set("user_properties", "{}")
+            .set("version_name", id)
+            .set(
+                "version_type",
+                detail.version_type.as_deref().unwrap_or("release"),
+            )
+            .set("game_directory", path_str(&paths.game_dir))
+            .set("assets_root", path_str(&paths.assets_dir))
+            .set("game_assets", path_str(game_assets))
+            .set("assets_index_name", asset_index_id)
+            .set("library_directory", path_str(&paths.libraries_dir))
+            .set("natives_directory", path_str(&paths.natives_dir))
+            .set("launcher_name", "AxLauncher")
+            .set("launcher_version", env!("CARGO_PKG_VERSION"))
+            .set("classpath_separator", Self::classpath_separator())
+            .set("classpath", classpath);
+
+        context
+    }
+
+    /// Build the command running Minecraft with Java runtime
+    fn build_command(
+        java: PathBuf,
+        paths: &LauncherPaths,
+        detail: &VersionDetail,
+        context: &LaunchContext,
+        logging_arguments: Vec<String>,
+        config: &AppConfig,
+    ) -> Command {
+        // Wrapper commands (eg. gamemoderun, prime-run) start java themselves
+        let mut wrapper = split_arguments(&config.wrapper_command).into_iter();
+        let mut cmd = match wrapper.next() {
+            Some(program) => {
+                let mut cmd = Command::new(program);
+                cmd.args(wrapper).arg(java);
+                cmd
+            }
+            None => Command::new(java),
+        };
+        cmd.current_dir(&paths.game_dir);
+
+        for variable in split_arguments(&config.environment_variables) {
+            if let Some((key, value)) = variable.split_once('=') {
+                cmd.env(key, value);
+            }
+        }
+
+        cmd.arg(format!("-Xmx{}M", config.allocated_ram));
+        if config.min_ram > 0 {
+            cmd.arg(format!("-Xms{}M", config.min_ram.min(config.allocated_ram)));
+        }
+        cmd.args(context.jvm_arguments(detail));
+        cmd.args(logging_arguments);
+        // User arguments come last so they override the defaults
+        cmd.args(split_arguments(&config.jvm_arguments));
+        cmd.arg(&detail.mainClass);
+        cmd.args(context.game_arguments(detail));
+        cmd.args(split_arguments(&config.game_arguments));
+
+        cmd
     }
 
     /// Returns java runtime path (binary - executable)
-    /// Searches for binary at `{find_dir}/java_runtime`
-    /// Install if not found
-    async fn get_java_path(find_dir: &Path) -> Result<PathBuf, String> {
-        let java_path = LauncherRepository::find_java(find_dir);
-        if java_path.exists() {
-            Ok(java_path)
-        } else {
-            let download_java_path = LauncherRepository::download_and_extract_java(
-                &find_dir.join("java_runtime").into(),
-            )
-            .await
-            .unwrap();
-            Ok(download_java_path)
+    /// `java_path` from settings is used when set, otherwise the runtime matching
+    /// `javaVersion` of the version is searched in `runtime_root` and installed if not found
+    pub async fn get_java_path(
+        detail: &VersionDetail,
+        java_path: &str,
+        runtime_root: &Path,
+        full_check: bool,
+        progress: &ProgressSender,
+    ) -> Result<PathBuf, String> {
+        if !java_path.is_empty() {
+            return Ok(PathBuf::from(java_path));
         }
+
+        let java_version = detail.java_version.clone().unwrap_or_default();
+        JavaService::ensure_runtime(&java_version, runtime_root, full_check, progress).await
     }
 
     pub fn get_local_minecraft_versions() -> Result<Vec<MinecraftVersion>, Error> {
@@ -310,14 +827,156 @@ impl LauncherService {
         let list_dir = fs::read_dir(minecraft_root_dir)?;
 
         let minecraft_versions: Vec<MinecraftVersion> = list_dir
-            .map(|v| MinecraftVersion {
-                id: v.unwrap().file_name().into_string().unwrap(),
-                version_type: "modded".to_owned(),
-                url: None,
-                available: true,
+            .map(|v| {
+                let v = v.unwrap();
+                let id = v.file_name().into_string().unwrap();
+
+                // Versions created by mod loaders inherit from the game version
+                let detail = Self::read_version_json(&v.path().join(format!("{}.json", id))).ok();
+                let version_type = match detail {
+                    Some(detail) if detail.inherits_from.is_none() => {
+                        detail.version_type.unwrap_or_else(|| "release".to_owned())
+                    }
+                    _ => "modded".to_owned(),
+                };
+
+                MinecraftVersion {
+                    id,
+                    version_type,
+                    url: None,
+                    available: true,
+                }
             })
             .collect();
 
         Ok(minecraft_versions)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORGE_1_12_2: &str = r#"{
+        "id": "1.12.2-forge-14.23.5.2859",
+        "inheritsFrom": "1.12.2",
+        "releaseTime": "2017-09-18T08:39:46+00:00",
+        "type": "release",
+        "mainClass": "net.minecraft.launchwrapper.Launch",
+        "minecraftArguments": "--username ${auth_player_name} --version ${version_name} --gameDir ${game_directory} --assetsDir ${assets_root} --assetIndex ${assets_index_name} --uuid ${auth_uuid} --accessToken ${auth_access_token} --userType ${user_type} --tweakClass net.minecraftforge.fml.common.launcher.FMLTweaker --versionType Forge",
+        "libraries": [
+            { "name": "net.minecraftforge:forge:1.12.2-14.23.5.2859" },
+            { "name": "net.minecraft:launchwrapper:1.12" }
+        ]
+    }"#;
+
+    /// OptiFine stacked on Forge, tweakers ordered as the game expects
+    const OPTIFINE_ON_FORGE: &str = r#"{
+        "id": "1.12.2-forge-OptiFine_HD_U_G5",
+        "inheritsFrom": "1.12.2-forge-14.23.5.2859",
+        "type": "release",
+        "mainClass": "net.minecraft.launchwrapper.Launch",
+        "minecraftArguments": "--username ${auth_player_name} --version ${version_name} --gameDir ${game_directory} --assetsDir ${assets_root} --assetIndex ${assets_index_name} --uuid ${auth_uuid} --accessToken ${auth_access_token} --userType ${user_type} --tweakClass optifine.OptiFineForgeTweaker --tweakClass net.minecraftforge.fml.common.launcher.FMLTweaker --versionType Forge",
+        "libraries": [
+            { "name": "optifine:OptiFine:1.12.2_HD_U_G5" },
+            { "name": "optifine:launchwrapper-of:2.1" }
+        ]
+    }"#;
+
+    const VANILLA_1_12_2: &str = r#"{
+        "id": "1.12.2",
+        "releaseTime": "2017-09-18T08:39:46+00:00",
+        "type": "release",
+        "mainClass": "net.minecraft.client.main.Main",
+        "minecraftArguments": "--username ${auth_player_name} --version ${version_name} --gameDir ${game_directory} --assetsDir ${assets_root} --assetIndex ${assets_index_name} --uuid ${auth_uuid} --accessToken ${auth_access_token} --userType ${user_type} --versionType ${version_type}",
+        "downloads": {
+            "client": {
+                "sha1": "0f275bc1547d01fa5f56ba34bdc87d981ee12daf",
+                "size": 10180113,
+                "url": "https://launcher.mojang.com/v1/objects/0f275bc1547d01fa5f56ba34bdc87d981ee12daf/client.jar"
+            }
+        },
+        "libraries": [
+            { "name": "net.sf.jopt-simple:jopt-simple:5.0.3" }
+        ]
+    }"#;
+
+    fn version(json: &str) -> VersionDetail {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn child_minecraft_arguments_replace_parent() {
+        let child = version(OPTIFINE_ON_FORGE);
+        let expected = child.minecraft_arguments.clone();
+
+        let merged = LauncherService::merge_version(child, version(FORGE_1_12_2));
+
+        assert_eq!(merged.minecraft_arguments, expected);
+        let arguments = split_arguments(merged.minecraft_arguments.as_deref().unwrap());
+        let tweakers: Vec<&str> = arguments
+            .windows(2)
+            .filter(|pair| pair[0] == "--tweakClass")
+            .map(|pair| pair[1].as_str())
+            .collect();
+        assert_eq!(
+            tweakers,
+            [
+                "optifine.OptiFineForgeTweaker",
+                "net.minecraftforge.fml.common.launcher.FMLTweaker",
+            ]
+        );
+    }
+
+    #[test]
+    fn child_without_minecraft_arguments_keeps_parent() {
+        let mut child = version(OPTIFINE_ON_FORGE);
+        child.minecraft_arguments = None;
+        let parent = version(FORGE_1_12_2);
+        let expected = parent.minecraft_arguments.clone();
+
+        let merged = LauncherService::merge_version(child, parent);
+
+        assert_eq!(merged.minecraft_arguments, expected);
+    }
+
+    #[test]
+    fn merge_keeps_parent_libraries_and_release_time() {
+        let merged =
+            LauncherService::merge_version(version(OPTIFINE_ON_FORGE), version(FORGE_1_12_2));
+
+        let names: Vec<&str> = merged
+            .libraries
+            .iter()
+            .map(|lib| lib.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "optifine:OptiFine:1.12.2_HD_U_G5",
+                "optifine:launchwrapper-of:2.1",
+                "net.minecraftforge:forge:1.12.2-14.23.5.2859",
+                "net.minecraft:launchwrapper:1.12",
+            ]
+        );
+        assert_eq!(merged.inherits_from.as_deref(), Some("1.12.2"));
+        assert_eq!(
+            merged.release_time.as_deref(),
+            Some("2017-09-18T08:39:46+00:00")
+        );
+    }
+
+    #[test]
+    fn merge_uses_client_jar_of_base_game() {
+        let merged =
+            LauncherService::merge_version(version(OPTIFINE_ON_FORGE), version(FORGE_1_12_2));
+        assert_eq!(merged.jar, None);
+
+        let merged = LauncherService::merge_version(merged, version(VANILLA_1_12_2));
+
+        assert_eq!(merged.jar.as_deref(), Some("1.12.2"));
+        assert_eq!(merged.mainClass, "net.minecraft.launchwrapper.Launch");
+        assert!(merged.downloads.is_some());
+        assert_eq!(merged.inherits_from, None);
+    }
+}
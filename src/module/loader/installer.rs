@@ -0,0 +1,333 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use tokio::process::Command;
+
+use crate::module::download::repository::DownloadRepository;
+use crate::module::launcher::maven::MavenCoordinate;
+use crate::module::launcher::service::LauncherService;
+use crate::module::loader::model::{InstallProfile, Processor};
+
+/// Directory of installer holding libraries not published to a Maven repository
+const INSTALLER_MAVEN_DIR: &str = "maven/";
+
+/// Side processors and data are resolved for
+const SIDE: &str = "client";
+
+/// Forge and NeoForge installer jar
+pub struct ForgeInstaller {
+    archive: zip::ZipArchive<fs::File>,
+    profile: InstallProfile,
+}
+
+impl ForgeInstaller {
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let file = fs::File::open(path).map_err(|e| e.to_string())?;
+        let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+
+        let content = Self::read_entry(&mut archive, "install_profile.json")?;
+        let profile: InstallProfile =
+            serde_json::from_slice(&content).map_err(|e| format!("install_profile.json: {}", e))?;
+
+        Ok(Self { archive, profile })
+    }
+
+    pub fn profile(&self) -> &InstallProfile {
+        &self.profile
+    }
+
+    /// Game version the loader is installed on
+    pub fn game_version(&self) -> &str {
+        match &self.profile.install {
+            Some(install) => &install.minecraft,
+            None => &self.profile.minecraft,
+        }
+    }
+
+    /// Version JSON registered in `versions/`, inheriting from the game version
+    pub fn version_json(&mut self) -> Result<String, String> {
+        if let Some(version_info) = &self.profile.version_info {
+            return serde_json::to_string_pretty(version_info).map_err(|e| e.to_string());
+        }
+
+        let name = self.profile.json.as_deref().unwrap_or("/version.json");
+        let content = Self::read_entry(&mut self.archive, name.trim_start_matches('/'))?;
+
+        String::from_utf8(content).map_err(|e| e.to_string())
+    }
+
+    /// Extract libraries shipped inside the installer (eg. forge universal jar)
+    pub fn extract_libraries(&mut self, libraries_dir: &Path) -> Result<(), String> {
+        for i in 0..self.archive.len() {
+            let mut entry = self.archive.by_index(i).map_err(|e| e.to_string())?;
+            let Some(path) = entry.enclosed_name() else {
+                continue;
+            };
+            let Ok(relative) = path.strip_prefix(INSTALLER_MAVEN_DIR) else {
+                continue;
+            };
+            if entry.is_dir() {
+                continue;
+            }
+
+            let dest = libraries_dir.join(relative);
+            Self::write_entry(&mut entry, &dest)?;
+        }
+
+        // Legacy installers ship the universal jar at the root
+        if let Some(install) = &self.profile.install {
+            let coordinate = MavenCoordinate::parse(&install.path)
+                .ok_or_else(|| format!("Invalid library: {}", install.path))?;
+            let dest = libraries_dir.join(coordinate.path());
+
+            let mut entry = self
+                .archive
+                .by_name(&install.file_path)
+                .map_err(|e| format!("{}: {}", install.file_path, e))?;
+            Self::write_entry(&mut entry, &dest)?;
+        }
+
+        Ok(())
+    }
+
+    /// Resolve client values of `data`, files inside the installer are extracted into `temp_dir`
+    /// `builtins` holds values provided by the launcher (eg. MINECRAFT_JAR, SIDE)
+    pub fn resolve_data(
+        &mut self,
+        libraries_dir: &Path,
+        temp_dir: &Path,
+        builtins: HashMap<String, String>,
+    ) -> Result<HashMap<String, String>, String> {
+        let mut data = builtins;
+
+        for (key, value) in &self.profile.data {
+            let value = &value.client;
+
+            let resolved = if let Some(file) = value.strip_prefix('/') {
+                let dest = temp_dir.join(file);
+                let mut entry = self
+                    .archive
+                    .by_name(file)
+                    .map_err(|e| format!("{}: {}", file, e))?;
+                Self::write_entry(&mut entry, &dest)?;
+                dest.to_string_lossy().into_owned()
+            } else {
+                Self::resolve_value(value, libraries_dir)?
+            };
+
+            data.insert(key.clone(), resolved);
+        }
+
+        Ok(data)
+    }
+
+    /// Run client processors in order with `java`
+    /// Processors whose outputs are already valid are skipped
+    pub async fn run_processors(
+        &self,
+        java: &Path,
+        data: &HashMap<String, String>,
+        libraries_dir: &Path,
+    ) -> Result<(), String> {
+        for processor in &self.profile.processors {
+            let client_side = processor
+                .sides
+                .as_ref()
+                .is_none_or(|sides| sides.iter().any(|side| side == SIDE));
+            if !client_side {
+                continue;
+            }
+
+            let outputs = Self::resolve_outputs(processor, data, libraries_dir)?;
+            if !outputs.is_empty()
+                && outputs
+                    .iter()
+                    .all(|(path, sha1)| Self::is_output_valid(path, sha1))
+            {
+                continue;
+            }
+
+            Self::run_processor(processor, java, data, libraries_dir).await?;
+
+            for (path, sha1) in &outputs {
+                if !Self::is_output_valid(path, sha1) {
+                    let _ = fs::remove_file(path);
+                    return Err(format!(
+                        "Processor {} produced an invalid {}",
+                        processor.jar,
+                        path.display()
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn run_processor(
+        processor: &Processor,
+        java: &Path,
+        data: &HashMap<String, String>,
+        libraries_dir: &Path,
+    ) -> Result<(), String> {
+        let jar = Self::library_path(&processor.jar, libraries_dir)?;
+        let main_class = Self::main_class(&jar)?;
+
+        let mut classpath = vec![jar.to_string_lossy().into_owned()];
+        for library in &processor.classpath {
+            classpath.push(
+                Self::library_path(library, libraries_dir)?
+                    .to_string_lossy()
+                    .into_owned(),
+            );
+        }
+
+        let args = processor
+            .args
+            .iter()
+            .map(|arg| Self::resolve_argument(arg, data, libraries_dir))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let output = Command::new(java)
+            .arg("-cp")
+            .arg(classpath.join(LauncherService::classpath_separator()))
+            .arg(&main_class)
+            .args(args)
+            .output()
+            .await
+            .map_err(|e| format!("Failed to run {}: {}", processor.jar, e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let tail: Vec<&str> = stderr.lines().rev().take(5).collect();
+
+            return Err(format!(
+                "Processor {} exited with {}\n{}",
+                processor.jar,
+                output.status,
+                tail.into_iter().rev().collect::<Vec<_>>().join("\n")
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Output paths with expected SHA-1, both sides may use `{KEY}` placeholders
+    fn resolve_outputs(
+        processor: &Processor,
+        data: &HashMap<String, String>,
+        libraries_dir: &Path,
+    ) -> Result<Vec<(PathBuf, String)>, String> {
+        processor
+            .outputs
+            .iter()
+            .map(|(path, sha1)| {
+                Ok((
+                    PathBuf::from(Self::resolve_argument(path, data, libraries_dir)?),
+                    Self::resolve_argument(sha1, data, libraries_dir)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn is_output_valid(path: &Path, sha1: &str) -> bool {
+        DownloadRepository::sha1_file(path).is_ok_and(|actual| actual.eq_ignore_ascii_case(sha1))
+    }
+
+    /// `[group:artifact:version]` is a library path, `'text'` is a literal
+    fn resolve_value(value: &str, libraries_dir: &Path) -> Result<String, String> {
+        if let Some(coordinate) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+            return Ok(Self::library_path(coordinate, libraries_dir)?
+                .to_string_lossy()
+                .into_owned());
+        }
+
+        if let Some(literal) = value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+            return Ok(literal.to_owned());
+        }
+
+        Ok(value.to_owned())
+    }
+
+    /// Replace `{KEY}` placeholders with data values, then resolve the argument as a value
+    fn resolve_argument(
+        arg: &str,
+        data: &HashMap<String, String>,
+        libraries_dir: &Path,
+    ) -> Result<String, String> {
+        if arg.starts_with('[') || arg.starts_with('\'') {
+            return Self::resolve_value(arg, libraries_dir);
+        }
+
+        let mut output = String::with_capacity(arg.len());
+        let mut rest = arg;
+
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}') else {
+                break;
+            };
+
+            let key = &rest[start + 1..start + end];
+            let value = data
+                .get(key)
+                .ok_or_else(|| format!("Unknown processor data: {}", key))?;
+
+            output.push_str(&rest[..start]);
+            output.push_str(value);
+            rest = &rest[start + end + 1..];
+        }
+        output.push_str(rest);
+
+        Ok(output)
+    }
+
+    fn library_path(coordinate: &str, libraries_dir: &Path) -> Result<PathBuf, String> {
+        MavenCoordinate::parse(coordinate)
+            .map(|coordinate| libraries_dir.join(coordinate.path()))
+            .ok_or_else(|| format!("Invalid library: {}", coordinate))
+    }
+
+    /// Read `Main-Class` from the manifest of a jar
+    fn main_class(jar: &Path) -> Result<String, String> {
+        let file = fs::File::open(jar).map_err(|e| format!("{}: {}", jar.display(), e))?;
+        let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+        let manifest = Self::read_entry(&mut archive, "META-INF/MANIFEST.MF")?;
+        let manifest = String::from_utf8_lossy(&manifest);
+
+        // Lines longer than 72 bytes continue on the next line after a space
+        let manifest = manifest.replace("\r\n", "\n").replace("\n ", "");
+
+        manifest
+            .lines()
+            .find_map(|line| line.strip_prefix("Main-Class:"))
+            .map(|main_class| main_class.trim().to_owned())
+            .ok_or_else(|| format!("{} has no Main-Class", jar.display()))
+    }
+
+    fn read_entry(archive: &mut zip::ZipArchive<fs::File>, name: &str) -> Result<Vec<u8>, String> {
+        let mut entry = archive
+            .by_name(name)
+            .map_err(|e| format!("{}: {}", name, e))?;
+
+        let mut content = Vec::new();
+        entry.read_to_end(&mut content).map_err(|e| e.to_string())?;
+
+        Ok(content)
+    }
+
+    fn write_entry(entry: &mut impl Read, dest: &Path) -> Result<(), String> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+
+        let mut file = fs::File::create(dest).map_err(|e| e.to_string())?;
+        std::io::copy(entry, &mut file).map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+}
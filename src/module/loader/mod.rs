@@ -0,0 +1,8 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod installer;
+pub mod model;
+pub mod optifine;
+pub mod repository;
+pub mod service;
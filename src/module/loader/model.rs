@@ -0,0 +1,102 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::module::launcher::model::Library;
+
+/// Element of `/versions/loader/{game_version}` returned by Fabric and Quilt meta
+#[derive(Deserialize, Debug)]
+pub struct MetaLoaderEntry {
+    pub loader: MetaLoader,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct MetaLoader {
+    pub version: String,
+    /// Only provided by Fabric
+    pub stable: Option<bool>,
+}
+
+/// Loader version compatible with a game version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoaderVersion {
+    pub version: String,
+    pub stable: bool,
+}
+
+impl From<MetaLoader> for LoaderVersion {
+    fn from(loader: MetaLoader) -> Self {
+        // Quilt marks unstable builds in the version (eg. 0.20.0-beta.9)
+        let stable = loader.stable.unwrap_or(!loader.version.contains('-'));
+
+        Self {
+            version: loader.version,
+            stable,
+        }
+    }
+}
+
+impl fmt::Display for LoaderVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.stable {
+            write!(f, "{}", self.version)
+        } else {
+            write!(f, "{} (unstable)", self.version)
+        }
+    }
+}
+
+/// `install_profile.json` of a Forge or NeoForge installer
+/// Installers before 1.13 (spec 0) only provide `install` and `versionInfo`
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct InstallProfile {
+    pub spec: u32,
+    /// Game version the loader is installed on
+    pub minecraft: String,
+    /// Path of the version JSON inside the installer, eg. "/version.json"
+    pub json: Option<String>,
+    /// Values of `{KEY}` placeholders used by processors
+    pub data: HashMap<String, SidedData>,
+    pub processors: Vec<Processor>,
+    /// Libraries needed by processors only
+    pub libraries: Vec<Library>,
+
+    pub install: Option<LegacyInstall>,
+    #[serde(rename = "versionInfo")]
+    pub version_info: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct SidedData {
+    pub client: String,
+}
+
+/// Java program run after libraries are installed (eg. binary patching the client jar)
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct Processor {
+    /// Maven coordinate of the processor jar
+    pub jar: String,
+    pub classpath: Vec<String>,
+    pub args: Vec<String>,
+    /// Files produced by the processor with their expected SHA-1
+    pub outputs: HashMap<String, String>,
+    /// Runs on every side when missing
+    pub sides: Option<Vec<String>>,
+}
+
+/// Universal jar of legacy installers
+#[derive(Deserialize, Debug)]
+pub struct LegacyInstall {
+    /// Maven coordinate of the universal jar
+    pub path: String,
+    #[serde(rename = "filePath")]
+    pub file_path: String,
+    pub minecraft: String,
+}
@@ -0,0 +1,195 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use tokio::process::Command;
+
+use crate::module::launcher::maven::MavenCoordinate;
+
+/// Classes holding the version constant, depending on the OptiFine release
+const CONFIG_CLASSES: [&str; 3] = [
+    "net/optifine/Config.class",
+    "notch/net/optifine/Config.class",
+    "Config.class",
+];
+
+/// Patches the client jar into the OptiFine library, absent from releases shipping patched classes
+const PATCHER_CLASS: &str = "optifine/Patcher.class";
+
+/// LaunchWrapper fork bundled with OptiFine, its version is written in the text file
+const LAUNCHWRAPPER_OF_VERSION: &str = "launchwrapper-of.txt";
+const LAUNCHWRAPPER_2: &str = "launchwrapper-2.0.jar";
+/// LaunchWrapper of Mojang, used by OptiFine releases without their own
+const LAUNCHWRAPPER: &str = "net.minecraft:launchwrapper:1.12";
+
+pub const OPTIFINE_TWEAKER: &str = "optifine.OptiFineTweaker";
+
+/// Version of an OptiFine jar, eg. `OptiFine_1.12.2_HD_U_G5`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptiFineVersion {
+    pub game_version: String,
+    /// Edition and release, eg. `HD_U_G5`
+    pub release: String,
+}
+
+impl OptiFineVersion {
+    pub fn parse(version: &str) -> Option<Self> {
+        let (game_version, release) = version.strip_prefix("OptiFine_")?.split_once('_')?;
+
+        Some(Self {
+            game_version: game_version.to_owned(),
+            release: release.to_owned(),
+        })
+    }
+
+    /// Id of the standalone version, the same the OptiFine installer creates
+    pub fn id(&self) -> String {
+        format!("{}-OptiFine_{}", self.game_version, self.release)
+    }
+
+    /// Library holding the OptiFine classes
+    pub fn library(&self) -> String {
+        format!("optifine:OptiFine:{}_{}", self.game_version, self.release)
+    }
+}
+
+/// OptiFine jar downloaded by the user
+pub struct OptiFineJar {
+    path: PathBuf,
+    archive: zip::ZipArchive<fs::File>,
+    version: OptiFineVersion,
+}
+
+impl OptiFineJar {
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let file = fs::File::open(path).map_err(|e| e.to_string())?;
+        let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+
+        let version = CONFIG_CLASSES
+            .iter()
+            .find_map(|class| Self::read_entry(&mut archive, class).ok())
+            .and_then(|class| Self::find_version(&class))
+            .ok_or_else(|| format!("{} is not an OptiFine jar", path.display()))?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            archive,
+            version,
+        })
+    }
+
+    pub fn version(&self) -> &OptiFineVersion {
+        &self.version
+    }
+
+    /// Install the OptiFine library, patching `client_jar` when the jar ships a patcher
+    pub async fn install_library(
+        &self,
+        java: &Path,
+        client_jar: &Path,
+        libraries_dir: &Path,
+    ) -> Result<(), String> {
+        let dest = Self::library_path(&self.version.library(), libraries_dir)?;
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+
+        if self.archive.index_for_name(PATCHER_CLASS).is_none() {
+            fs::copy(&self.path, &dest).map_err(|e| e.to_string())?;
+            return Ok(());
+        }
+
+        let output = Command::new(java)
+            .arg("-cp")
+            .arg(&self.path)
+            .arg("optifine.Patcher")
+            .arg(client_jar)
+            .arg(&self.path)
+            .arg(&dest)
+            .output()
+            .await
+            .map_err(|e| format!("Failed to run OptiFine patcher: {}", e))?;
+
+        if !output.status.success() {
+            let _ = fs::remove_file(&dest);
+            return Err(format!(
+                "OptiFine patcher exited with {}\n{}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Extract the LaunchWrapper used by this release, returns its library name
+    pub fn install_launchwrapper(&mut self, libraries_dir: &Path) -> Result<String, String> {
+        let (library, entry) =
+            if let Ok(version) = Self::read_entry(&mut self.archive, LAUNCHWRAPPER_OF_VERSION) {
+                let version = String::from_utf8_lossy(&version).trim().to_owned();
+                (
+                    format!("optifine:launchwrapper-of:{}", version),
+                    format!("launchwrapper-of-{}.jar", version),
+                )
+            } else if self.archive.index_for_name(LAUNCHWRAPPER_2).is_some() {
+                (
+                    "optifine:launchwrapper:2.0".to_owned(),
+                    LAUNCHWRAPPER_2.to_owned(),
+                )
+            } else {
+                return Ok(LAUNCHWRAPPER.to_owned());
+            };
+
+        let content = Self::read_entry(&mut self.archive, &entry)?;
+        let dest = Self::library_path(&library, libraries_dir)?;
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(&dest, content).map_err(|e| e.to_string())?;
+
+        Ok(library)
+    }
+
+    /// Version is a string constant like `OptiFine_1.12.2_HD_U_G5` of the config class
+    fn find_version(class: &[u8]) -> Option<OptiFineVersion> {
+        let prefix = b"OptiFine_";
+
+        class
+            .windows(prefix.len())
+            .enumerate()
+            .filter(|(_, window)| *window == prefix)
+            .find_map(|(start, _)| {
+                let version: String = class[start..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_alphanumeric() || **b == b'_' || **b == b'.')
+                    .map(|b| *b as char)
+                    .collect();
+
+                OptiFineVersion::parse(&version).filter(|version| {
+                    version
+                        .game_version
+                        .starts_with(|c: char| c.is_ascii_digit())
+                })
+            })
+    }
+
+    fn library_path(name: &str, libraries_dir: &Path) -> Result<PathBuf, String> {
+        MavenCoordinate::parse(name)
+            .map(|coordinate| libraries_dir.join(coordinate.path()))
+            .ok_or_else(|| format!("Invalid library: {}", name))
+    }
+
+    fn read_entry(archive: &mut zip::ZipArchive<fs::File>, name: &str) -> Result<Vec<u8>, String> {
+        let mut entry = archive
+            .by_name(name)
+            .map_err(|e| format!("{}: {}", name, e))?;
+
+        let mut content = Vec::new();
+        entry.read_to_end(&mut content).map_err(|e| e.to_string())?;
+
+        Ok(content)
+    }
+}
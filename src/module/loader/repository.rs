@@ -0,0 +1,102 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::module::loader::model::{LoaderVersion, MetaLoaderEntry};
+
+pub const FORGE_MAVEN_URL: &str = "https://maven.minecraftforge.net/";
+pub const NEOFORGE_MAVEN_URL: &str = "https://maven.neoforged.net/releases/";
+
+static METADATA_VERSION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<version>([^<]+)</version>").unwrap());
+
+/// Client of Fabric and Quilt meta servers, both expose the same routes
+/// under a different API version (Fabric v2, Quilt v3)
+pub struct LoaderRepository {}
+
+impl LoaderRepository {
+    /// Loader versions compatible with a game version, newest first
+    pub async fn get_loader_versions(
+        meta_url: &str,
+        api_version: &str,
+        game_version: &str,
+    ) -> Result<Vec<LoaderVersion>, String> {
+        let url = format!(
+            "{}/{}/versions/loader/{}",
+            meta_url.trim_end_matches('/'),
+            api_version,
+            game_version
+        );
+
+        let entries = reqwest::get(&url)
+            .await
+            .map_err(|e| e.to_string())?
+            .error_for_status()
+            .map_err(|e| e.to_string())?
+            .json::<Vec<MetaLoaderEntry>>()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(entries
+            .into_iter()
+            .map(|entry| LoaderVersion::from(entry.loader))
+            .collect())
+    }
+
+    /// Version JSON of the loader, inheriting from the game version
+    /// Returned as text so it is written to disk unchanged
+    pub async fn get_profile(
+        meta_url: &str,
+        api_version: &str,
+        game_version: &str,
+        loader_version: &str,
+    ) -> Result<String, String> {
+        let url = format!(
+            "{}/{}/versions/loader/{}/{}/profile/json",
+            meta_url.trim_end_matches('/'),
+            api_version,
+            game_version,
+            loader_version
+        );
+
+        reqwest::get(&url)
+            .await
+            .map_err(|e| e.to_string())?
+            .error_for_status()
+            .map_err(|e| e.to_string())?
+            .text()
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Every version listed in `maven-metadata.xml` of an artifact, oldest first
+    pub async fn get_maven_versions(
+        repository: &str,
+        group: &str,
+        artifact: &str,
+    ) -> Result<Vec<String>, String> {
+        let url = format!(
+            "{}{}/{}/maven-metadata.xml",
+            repository,
+            group.replace('.', "/"),
+            artifact
+        );
+
+        let metadata = reqwest::get(&url)
+            .await
+            .map_err(|e| e.to_string())?
+            .error_for_status()
+            .map_err(|e| e.to_string())?
+            .text()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(METADATA_VERSION
+            .captures_iter(&metadata)
+            .map(|caps| caps[1].to_owned())
+            .collect())
+    }
+}
@@ -0,0 +1,480 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::module::config::repository::ConfigRepository;
+use crate::module::download::entity::DownloadTask;
+use crate::module::download::service::{DownloadService, MAX_CONCURRENT_DOWNLOADS, ProgressSender};
+use crate::module::instance::model::{Instance, ModLoader};
+use crate::module::instance::service::InstanceService;
+use crate::module::java::service::JavaService;
+use crate::module::launcher::maven::MavenCoordinate;
+use crate::module::launcher::model::VersionDetail;
+use crate::module::launcher::rule::RuleEvaluator;
+use crate::module::launcher::service::LauncherService;
+use crate::module::loader::installer::ForgeInstaller;
+use crate::module::loader::model::LoaderVersion;
+use crate::module::loader::optifine::{OPTIFINE_TWEAKER, OptiFineJar};
+use crate::module::loader::repository::{FORGE_MAVEN_URL, LoaderRepository, NEOFORGE_MAVEN_URL};
+
+/// Written next to a Forge / NeoForge installer once installed, holds id of the version it created
+const INSTALLED_MARKER: &str = "installed-version.txt";
+
+/// NeoForge for 1.20.1 kept the `forge` artifact and versions of Forge, eg. 1.20.1-47.1.106
+const NEOFORGE_LEGACY_GAME_VERSION: &str = "1.20.1";
+
+pub struct LoaderService {}
+
+impl LoaderService {
+    /// Loader versions available for a game version, newest first
+    pub async fn get_versions(
+        loader: ModLoader,
+        game_version: &str,
+    ) -> Result<Vec<LoaderVersion>, String> {
+        match loader {
+            ModLoader::Forge => Self::get_forge_versions(game_version).await,
+            ModLoader::NeoForge => Self::get_neoforge_versions(game_version).await,
+            loader => {
+                let (meta_url, api_version) = Self::meta(loader)?;
+                LoaderRepository::get_loader_versions(&meta_url, api_version, game_version).await
+            }
+        }
+    }
+
+    /// Install the loader of an instance, returns id of the version to launch
+    /// Vanilla needs nothing, the newest stable loader is pinned on the instance the first time
+    /// so later launches don't need the network and never switch loader versions
+    pub async fn install(
+        instance: &mut Instance,
+        progress: &ProgressSender,
+    ) -> Result<String, String> {
+        let loader = instance.loader;
+        if loader == ModLoader::Vanilla {
+            return Ok(instance.version.clone());
+        }
+
+        let loader_version = match &instance.loader_version {
+            Some(version) => version.clone(),
+            None => {
+                let version = Self::latest_stable(loader, &instance.version).await?;
+                instance.loader_version = Some(version.clone());
+                InstanceService::save(instance)?;
+                version
+            }
+        };
+
+        match loader {
+            ModLoader::Forge | ModLoader::NeoForge => {
+                Self::install_forge(loader, &instance.version, &loader_version, progress).await
+            }
+            loader => {
+                Self::install_from_meta(loader, &instance.version, &loader_version, progress).await
+            }
+        }
+    }
+
+    /// Fabric and Quilt publish a ready to use version JSON on their meta server
+    async fn install_from_meta(
+        loader: ModLoader,
+        game_version: &str,
+        loader_version: &str,
+        progress: &ProgressSender,
+    ) -> Result<String, String> {
+        let (meta_url, api_version) = Self::meta(loader)?;
+
+        let root_dir = LauncherService::get_minecraft_root_dir().map_err(|e| e.to_string())?;
+        let config = ConfigRepository::load();
+
+        // Profile ids look like fabric-loader-0.15.0-1.20.1
+        let mut id = format!(
+            "{}-loader-{}-{}",
+            loader.to_string().to_lowercase(),
+            loader_version,
+            game_version
+        );
+        let mut json_path = Self::version_json_path(&root_dir, &id);
+
+        let profile = match fs::read_to_string(&json_path) {
+            Ok(profile) => profile,
+            Err(_) => {
+                let profile = LoaderRepository::get_profile(
+                    &meta_url,
+                    api_version,
+                    game_version,
+                    loader_version,
+                )
+                .await?;
+
+                let detail: VersionDetail =
+                    serde_json::from_str(&profile).map_err(|e| e.to_string())?;
+                if !detail.id.is_empty() && detail.id != id {
+                    id = detail.id;
+                    json_path = Self::version_json_path(&root_dir, &id);
+                }
+
+                if let Some(parent) = json_path.parent() {
+                    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+                }
+                fs::write(&json_path, &profile).map_err(|e| e.to_string())?;
+
+                profile
+            }
+        };
+
+        // Loader libraries come from their own Maven repositories
+        let detail: VersionDetail = serde_json::from_str(&profile)
+            .map_err(|e| format!("{}: {}", json_path.display(), e))?;
+        let tasks = LauncherService::library_tasks(
+            &detail.libraries,
+            &root_dir.join("libraries"),
+            &RuleEvaluator::default(),
+            config.verify_file_hashes,
+        );
+        DownloadService::download_all(tasks, MAX_CONCURRENT_DOWNLOADS, progress).await?;
+
+        Ok(id)
+    }
+
+    /// Forge and NeoForge are installed by running the processors of their installer jar
+    /// The version JSON is written last, so an interrupted install is retried on next launch
+    async fn install_forge(
+        loader: ModLoader,
+        game_version: &str,
+        loader_version: &str,
+        progress: &ProgressSender,
+    ) -> Result<String, String> {
+        let root_dir = LauncherService::get_minecraft_root_dir().map_err(|e| e.to_string())?;
+        let libraries_dir = root_dir.join("libraries");
+        let config = ConfigRepository::load();
+
+        let (repository, installer, id) = match loader {
+            ModLoader::Forge => (
+                FORGE_MAVEN_URL,
+                format!(
+                    "net.minecraftforge:forge:{}-{}:installer",
+                    game_version, loader_version
+                ),
+                format!("{}-forge-{}", game_version, loader_version),
+            ),
+            _ if game_version == NEOFORGE_LEGACY_GAME_VERSION => (
+                NEOFORGE_MAVEN_URL,
+                format!(
+                    "net.neoforged:forge:{}-{}:installer",
+                    game_version, loader_version
+                ),
+                format!("{}-forge-{}", game_version, loader_version),
+            ),
+            _ => (
+                NEOFORGE_MAVEN_URL,
+                format!("net.neoforged:neoforge:{}:installer", loader_version),
+                format!("neoforge-{}", loader_version),
+            ),
+        };
+
+        let coordinate = MavenCoordinate::parse(&installer)
+            .ok_or_else(|| format!("Invalid library: {}", installer))?;
+        let installer_path = libraries_dir.join(coordinate.path());
+
+        // Legacy installers name the version themselves (eg. 1.7.10-Forge10.13.4.1614-1.7.10)
+        let marker_path = installer_path.with_file_name(INSTALLED_MARKER);
+        if let Ok(installed_id) = fs::read_to_string(&marker_path) {
+            let installed_id = installed_id.trim();
+            if !installed_id.is_empty()
+                && Self::version_json_path(&root_dir, installed_id).is_file()
+            {
+                return Ok(installed_id.to_owned());
+            }
+        }
+
+        if !installer_path.is_file() {
+            let task = DownloadTask::new(
+                &coordinate.url(repository),
+                installer_path.clone(),
+                None,
+                None,
+            );
+            DownloadService::download_all(vec![task], 1, progress).await?;
+        }
+
+        let mut installer = ForgeInstaller::open(&installer_path)?;
+        if installer.game_version() != game_version {
+            return Err(format!(
+                "{} {} is made for Minecraft {}",
+                loader,
+                loader_version,
+                installer.game_version()
+            ));
+        }
+
+        let version_json = installer.version_json()?;
+        let detail: VersionDetail =
+            serde_json::from_str(&version_json).map_err(|e| format!("version.json: {}", e))?;
+        let id = if detail.id.is_empty() {
+            id
+        } else {
+            detail.id.clone()
+        };
+
+        installer.extract_libraries(&libraries_dir)?;
+
+        // Libraries of processors and of the game itself
+        let rules = RuleEvaluator::default();
+        let mut tasks = LauncherService::library_tasks(
+            &installer.profile().libraries,
+            &libraries_dir,
+            &rules,
+            config.verify_file_hashes,
+        );
+        tasks.extend(LauncherService::library_tasks(
+            &detail.libraries,
+            &libraries_dir,
+            &rules,
+            config.verify_file_hashes,
+        ));
+        DownloadService::download_all(tasks, MAX_CONCURRENT_DOWNLOADS, progress).await?;
+
+        if !installer.profile().processors.is_empty() {
+            let (game_detail, client_jar) = LauncherService::prepare_client_jar(
+                game_version,
+                &root_dir,
+                config.verify_file_hashes,
+                progress,
+            )
+            .await?;
+            let java = LauncherService::get_java_path(
+                &game_detail,
+                &config.java_path,
+                &JavaService::runtime_root()?,
+                config.verify_file_hashes,
+                progress,
+            )
+            .await?;
+
+            let builtins = HashMap::from([
+                ("SIDE".to_owned(), "client".to_owned()),
+                (
+                    "MINECRAFT_JAR".to_owned(),
+                    client_jar.to_string_lossy().into_owned(),
+                ),
+                ("MINECRAFT_VERSION".to_owned(), game_version.to_owned()),
+                ("ROOT".to_owned(), root_dir.to_string_lossy().into_owned()),
+                (
+                    "INSTALLER".to_owned(),
+                    installer_path.to_string_lossy().into_owned(),
+                ),
+                (
+                    "LIBRARY_DIR".to_owned(),
+                    libraries_dir.to_string_lossy().into_owned(),
+                ),
+            ]);
+
+            let temp_dir = std::env::temp_dir().join(format!("axlauncher-{}", id));
+            let result = async {
+                let data = installer.resolve_data(&libraries_dir, &temp_dir, builtins)?;
+                installer.run_processors(&java, &data, &libraries_dir).await
+            }
+            .await;
+            let _ = fs::remove_dir_all(&temp_dir);
+            result?;
+        }
+
+        let json_path = Self::version_json_path(&root_dir, &id);
+        if let Some(parent) = json_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(&json_path, version_json).map_err(|e| e.to_string())?;
+        fs::write(&marker_path, &id).map_err(|e| e.to_string())?;
+
+        Ok(id)
+    }
+
+    /// Install OptiFine from a jar picked by the user
+    /// Vanilla instances switch to a standalone version inheriting from their game version,
+    /// instances with a mod loader get the jar in their mods directory
+    pub async fn install_optifine(
+        mut instance: Instance,
+        jar: PathBuf,
+        progress: &ProgressSender,
+    ) -> Result<Instance, String> {
+        let mut optifine = OptiFineJar::open(&jar)?;
+        let version = optifine.version().clone();
+
+        // Instances already on an OptiFine version get it replaced
+        let game_version = instance
+            .version
+            .split_once("-OptiFine_")
+            .map_or(instance.version.as_str(), |(game_version, _)| game_version);
+        if game_version != version.game_version {
+            return Err(format!(
+                "OptiFine {} is made for Minecraft {}, the instance uses {}",
+                version.release, version.game_version, game_version
+            ));
+        }
+
+        if instance.loader != ModLoader::Vanilla {
+            let file_name = jar
+                .file_name()
+                .ok_or_else(|| format!("Invalid file: {}", jar.display()))?;
+            let mods_dir = instance.path.join("mods");
+            fs::create_dir_all(&mods_dir).map_err(|e| e.to_string())?;
+            fs::copy(&jar, mods_dir.join(file_name)).map_err(|e| e.to_string())?;
+
+            return Ok(instance);
+        }
+
+        let root_dir = LauncherService::get_minecraft_root_dir().map_err(|e| e.to_string())?;
+        let libraries_dir = root_dir.join("libraries");
+        let config = ConfigRepository::load();
+
+        let (detail, client_jar) = LauncherService::prepare_client_jar(
+            &version.game_version,
+            &root_dir,
+            config.verify_file_hashes,
+            progress,
+        )
+        .await?;
+        let java = LauncherService::get_java_path(
+            &detail,
+            &config.java_path,
+            &JavaService::runtime_root()?,
+            config.verify_file_hashes,
+            progress,
+        )
+        .await?;
+
+        optifine
+            .install_library(&java, &client_jar, &libraries_dir)
+            .await?;
+        let launchwrapper = optifine.install_launchwrapper(&libraries_dir)?;
+
+        // Legacy versions replace minecraftArguments, newer ones append game arguments
+        let id = version.id();
+        let mut profile = serde_json::json!({
+            "id": id,
+            "inheritsFrom": version.game_version,
+            "type": "release",
+            "mainClass": "net.minecraft.launchwrapper.Launch",
+            "libraries": [
+                { "name": version.library() },
+                { "name": launchwrapper },
+            ],
+        });
+        match &detail.minecraft_arguments {
+            Some(arguments) => {
+                profile["minecraftArguments"] =
+                    format!("{} --tweakClass {}", arguments, OPTIFINE_TWEAKER).into();
+            }
+            None => {
+                profile["arguments"] = serde_json::json!({
+                    "game": ["--tweakClass", OPTIFINE_TWEAKER],
+                });
+            }
+        }
+
+        let json_path = Self::version_json_path(&root_dir, &id);
+        if let Some(parent) = json_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let profile = serde_json::to_string_pretty(&profile).map_err(|e| e.to_string())?;
+        fs::write(&json_path, profile).map_err(|e| e.to_string())?;
+
+        instance.version = id;
+        InstanceService::save(&instance)?;
+
+        Ok(instance)
+    }
+
+    /// Forge versions are published as `{game_version}-{forge_version}`
+    async fn get_forge_versions(game_version: &str) -> Result<Vec<LoaderVersion>, String> {
+        let prefix = format!("{}-", game_version);
+        let versions =
+            LoaderRepository::get_maven_versions(FORGE_MAVEN_URL, "net.minecraftforge", "forge")
+                .await?;
+
+        Ok(versions
+            .iter()
+            .rev()
+            .filter_map(|version| version.strip_prefix(&prefix))
+            .map(|version| LoaderVersion {
+                version: version.to_owned(),
+                stable: true,
+            })
+            .collect())
+    }
+
+    /// NeoForge versions drop the leading `1.` of the game version, eg. 1.21.1 is 21.1.x
+    /// except for 1.20.1, published under the Forge artifact
+    async fn get_neoforge_versions(game_version: &str) -> Result<Vec<LoaderVersion>, String> {
+        if game_version == NEOFORGE_LEGACY_GAME_VERSION {
+            let prefix = format!("{}-", game_version);
+            let versions =
+                LoaderRepository::get_maven_versions(NEOFORGE_MAVEN_URL, "net.neoforged", "forge")
+                    .await?;
+
+            return Ok(versions
+                .iter()
+                .rev()
+                .filter_map(|version| version.strip_prefix(&prefix))
+                .map(|version| LoaderVersion {
+                    version: version.to_owned(),
+                    stable: true,
+                })
+                .collect());
+        }
+
+        let Some(stripped) = game_version.strip_prefix("1.") else {
+            return Ok(Vec::new());
+        };
+        let prefix = if stripped.contains('.') {
+            format!("{}.", stripped)
+        } else {
+            format!("{}.0.", stripped)
+        };
+
+        let versions =
+            LoaderRepository::get_maven_versions(NEOFORGE_MAVEN_URL, "net.neoforged", "neoforge")
+                .await?;
+
+        Ok(versions
+            .iter()
+            .rev()
+            .filter(|version| version.starts_with(&prefix))
+            .map(|version| LoaderVersion {
+                version: version.clone(),
+                stable: !version.contains("beta"),
+            })
+            .collect())
+    }
+
+    fn version_json_path(root_dir: &Path, id: &str) -> PathBuf {
+        root_dir
+            .join("versions")
+            .join(id)
+            .join(format!("{}.json", id))
+    }
+
+    async fn latest_stable(loader: ModLoader, game_version: &str) -> Result<String, String> {
+        let versions = Self::get_versions(loader, game_version).await?;
+
+        versions
+            .iter()
+            .find(|version| version.stable)
+            .or(versions.first())
+            .map(|version| version.version.clone())
+            .ok_or_else(|| format!("{} doesn't support Minecraft {}", loader, game_version))
+    }
+
+    /// Meta server and API version of a loader, servers are configurable for testing
+    fn meta(loader: ModLoader) -> Result<(String, &'static str), String> {
+        let config = ConfigRepository::load();
+
+        match loader {
+            ModLoader::Fabric => Ok((config.fabric_meta_url, "v2")),
+            ModLoader::Quilt => Ok((config.quilt_meta_url, "v3")),
+            loader => Err(format!("{} is not installed from a meta server", loader)),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use crate::module::process::entity::OutputStream;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 6] = [
+        Self::Trace,
+        Self::Debug,
+        Self::Info,
+        Self::Warn,
+        Self::Error,
+        Self::Fatal,
+    ];
+
+    /// Parse log4j level name (eg. "INFO", "WARNING")
+    pub fn parse(level: &str) -> Option<Self> {
+        match level.to_ascii_uppercase().as_str() {
+            "TRACE" | "FINEST" | "FINER" => Some(Self::Trace),
+            "DEBUG" | "FINE" => Some(Self::Debug),
+            "INFO" | "CONFIG" => Some(Self::Info),
+            "WARN" | "WARNING" => Some(Self::Warn),
+            "ERROR" | "SEVERE" => Some(Self::Error),
+            "FATAL" => Some(Self::Fatal),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+            Self::Fatal => "FATAL",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Single log event of the game
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    /// Local time, eg. "12:34:56"
+    pub time: Option<String>,
+    pub level: LogLevel,
+    pub thread: Option<String>,
+    pub logger: Option<String>,
+    pub message: String,
+    /// Stack trace attached to the event
+    pub throwable: Option<String>,
+    pub stream: OutputStream,
+}
+
+impl LogEntry {
+    /// Case insensitive search in message, thread, logger and stack trace
+    /// `query` must already be lowercase
+    pub fn matches(&self, query: &str) -> bool {
+        [
+            Some(self.message.as_str()),
+            self.thread.as_deref(),
+            self.logger.as_deref(),
+            self.throwable.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|field| field.to_lowercase().contains(query))
+    }
+}
+
+impl fmt::Display for LogEntry {
+    /// Same layout as the game's plain text log
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(time) = &self.time {
+            write!(f, "[{}] ", time)?;
+
+            match &self.thread {
+                Some(thread) => write!(f, "[{}/{}]", thread, self.level)?,
+                None => write!(f, "[{}]", self.level)?,
+            }
+
+            if let Some(logger) = &self.logger {
+                write!(f, " [{}]", logger)?;
+            }
+
+            write!(f, ": ")?;
+        }
+
+        write!(f, "{}", self.message)?;
+
+        if let Some(throwable) = &self.throwable {
+            write!(f, "\n{}", throwable)?;
+        }
+
+        Ok(())
+    }
+}
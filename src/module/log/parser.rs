@@ -0,0 +1,301 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::LazyLock;
+
+use chrono::{DateTime, Local};
+use regex::Regex;
+
+use crate::module::log::model::{LogEntry, LogLevel};
+use crate::module::process::entity::{OutputLine, OutputStream};
+
+/// `[12:34:56] [Render thread/INFO]: message`, optionally followed by `[logger]` (Forge)
+static PLAIN_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^\[(\d{2}:\d{2}:\d{2})(?:\.\d+)?\] \[([^\]]*)/([A-Za-z]+)\](?: \[([^\]]*)\])?: ?(.*)$",
+    )
+    .unwrap()
+});
+
+/// `2013-04-25 12:34:56 [INFO] message` used before 1.7
+static LEGACY_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:\d{4}-\d{2}-\d{2} )?(\d{2}:\d{2}:\d{2}) \[([A-Za-z]+)\] (.*)$").unwrap()
+});
+
+static XML_ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(\w+)="([^"]*)""#).unwrap());
+
+/// Events larger than this are shown as plain text
+const MAX_EVENT_SIZE: usize = 1024 * 1024;
+
+/// Turns game output into log entries
+/// log4j XML events (`<log4j:Event>`) can span several lines and are buffered until complete
+pub struct LogParser {
+    pending_event: Option<String>,
+    last_level: LogLevel,
+}
+
+impl Default for LogParser {
+    fn default() -> Self {
+        Self {
+            pending_event: None,
+            last_level: LogLevel::Info,
+        }
+    }
+}
+
+impl LogParser {
+    /// Feed one output line, returns the entry it completes
+    pub fn push(&mut self, line: &OutputLine) -> Option<LogEntry> {
+        if line.stream == OutputStream::Stdout
+            && (self.pending_event.is_some() || line.text.trim_start().starts_with("<log4j:Event"))
+        {
+            let event = self.pending_event.get_or_insert_with(String::new);
+            event.push_str(&line.text);
+            event.push('\n');
+
+            if !line.text.contains("</log4j:Event>") && event.len() < MAX_EVENT_SIZE {
+                return None;
+            }
+
+            let event = self.pending_event.take().unwrap_or_default();
+            let entry =
+                Self::parse_event(&event).unwrap_or_else(|| self.raw_entry(&event, line.stream));
+            self.last_level = entry.level;
+            return Some(entry);
+        }
+
+        let entry = self.parse_line(&line.text, line.stream);
+        self.last_level = entry.level;
+        Some(entry)
+    }
+
+    fn parse_line(&self, text: &str, stream: OutputStream) -> LogEntry {
+        if let Some(captures) = PLAIN_LINE.captures(text)
+            && let Some(level) = LogLevel::parse(&captures[3])
+        {
+            return LogEntry {
+                time: Some(captures[1].to_owned()),
+                level,
+                thread: Some(captures[2].to_owned()),
+                logger: captures
+                    .get(4)
+                    .map(|logger| logger.as_str().trim_end_matches('/').to_owned()),
+                message: captures[5].to_owned(),
+                throwable: None,
+                stream,
+            };
+        }
+
+        if let Some(captures) = LEGACY_LINE.captures(text)
+            && let Some(level) = LogLevel::parse(&captures[2])
+        {
+            return LogEntry {
+                time: Some(captures[1].to_owned()),
+                level,
+                thread: None,
+                logger: None,
+                message: captures[3].to_owned(),
+                throwable: None,
+                stream,
+            };
+        }
+
+        self.raw_entry(text, stream)
+    }
+
+    /// Lines without header (eg. stack traces) keep level of the previous entry
+    fn raw_entry(&self, text: &str, stream: OutputStream) -> LogEntry {
+        LogEntry {
+            time: None,
+            level: self.last_level,
+            thread: None,
+            logger: None,
+            message: text.trim_end().to_owned(),
+            throwable: None,
+            stream,
+        }
+    }
+
+    /// Parse a complete `<log4j:Event>` element
+    fn parse_event(event: &str) -> Option<LogEntry> {
+        let start = event.find("<log4j:Event")?;
+        let header_end = start + event[start..].find('>')?;
+        let header = &event[start..header_end];
+
+        let attribute = |name: &str| {
+            XML_ATTRIBUTE
+                .captures_iter(header)
+                .find(|captures| &captures[1] == name)
+                .map(|captures| Self::unescape(&captures[2]))
+        };
+
+        let time = attribute("timestamp")
+            .and_then(|timestamp| timestamp.parse::<i64>().ok())
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|time| time.with_timezone(&Local).format("%H:%M:%S").to_string());
+
+        Some(LogEntry {
+            time,
+            level: attribute("level")
+                .and_then(|level| LogLevel::parse(&level))
+                .unwrap_or(LogLevel::Info),
+            thread: attribute("thread"),
+            logger: attribute("logger").filter(|logger| !logger.is_empty()),
+            message: Self::element_text(event, "log4j:Message").unwrap_or_default(),
+            throwable: Self::element_text(event, "log4j:Throwable"),
+            stream: OutputStream::Stdout,
+        })
+    }
+
+    /// Returns text of an element, CDATA sections are kept as they are
+    fn element_text(event: &str, tag: &str) -> Option<String> {
+        let open = format!("<{}>", tag);
+        let close = format!("</{}>", tag);
+
+        let start = event.find(&open)? + open.len();
+        let end = start + event[start..].find(&close)?;
+        let content = event[start..end].trim();
+
+        let text = match content
+            .strip_prefix("<![CDATA[")
+            .and_then(|c| c.strip_suffix("]]>"))
+        {
+            Some(cdata) => cdata.to_owned(),
+            None => Self::unescape(content),
+        };
+
+        Some(text.trim_end().to_owned())
+    }
+
+    fn unescape(text: &str) -> String {
+        text.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stdout(text: &str) -> OutputLine {
+        OutputLine {
+            stream: OutputStream::Stdout,
+            text: text.to_owned(),
+        }
+    }
+
+    /// Feed every line, returns completed entries
+    fn parse(lines: &[&str]) -> Vec<LogEntry> {
+        let mut parser = LogParser::default();
+        lines
+            .iter()
+            .filter_map(|line| parser.push(&stdout(line)))
+            .collect()
+    }
+
+    #[test]
+    fn parses_xml_event_across_lines() {
+        let entries = parse(&[
+            r#"<log4j:Event logger="net.minecraft.client.Minecraft" timestamp="1700000000000" level="WARN" thread="Render thread">"#,
+            r#"  <log4j:Message><![CDATA[Missing sound for event: <minecraft:item.goat_horn.play> & more]]></log4j:Message>"#,
+            "</log4j:Event>",
+        ]);
+
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.level, LogLevel::Warn);
+        assert_eq!(entry.thread.as_deref(), Some("Render thread"));
+        assert_eq!(
+            entry.logger.as_deref(),
+            Some("net.minecraft.client.Minecraft")
+        );
+        assert_eq!(
+            entry.message,
+            "Missing sound for event: <minecraft:item.goat_horn.play> & more"
+        );
+        assert!(entry.time.is_some());
+        assert_eq!(entry.throwable, None);
+    }
+
+    #[test]
+    fn parses_escaped_message_and_throwable() {
+        let entries = parse(&[
+            r#"<log4j:Event logger="" timestamp="1700000000000" level="ERROR" thread="main">"#,
+            "  <log4j:Message>a &lt; b &amp;&amp; &quot;c&quot;</log4j:Message>",
+            "  <log4j:Throwable><![CDATA[java.lang.IllegalStateException: boom",
+            "\tat dxo.a(SourceFile:12)",
+            "]]></log4j:Throwable>",
+            "</log4j:Event>",
+        ]);
+
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.level, LogLevel::Error);
+        assert_eq!(entry.logger, None);
+        assert_eq!(entry.message, r#"a < b && "c""#);
+        assert_eq!(
+            entry.throwable.as_deref(),
+            Some("java.lang.IllegalStateException: boom\n\tat dxo.a(SourceFile:12)")
+        );
+    }
+
+    #[test]
+    fn parses_plain_lines() {
+        let entries = parse(&[
+            "[12:34:56] [Render thread/INFO]: Setting user: Steve",
+            "[12:34:57.123] [main/WARN] [net.minecraftforge.fml.loading/]: Mod file is invalid",
+        ]);
+
+        assert_eq!(entries[0].time.as_deref(), Some("12:34:56"));
+        assert_eq!(entries[0].thread.as_deref(), Some("Render thread"));
+        assert_eq!(entries[0].level, LogLevel::Info);
+        assert_eq!(entries[0].logger, None);
+        assert_eq!(entries[0].message, "Setting user: Steve");
+
+        assert_eq!(entries[1].level, LogLevel::Warn);
+        assert_eq!(
+            entries[1].logger.as_deref(),
+            Some("net.minecraftforge.fml.loading")
+        );
+        assert_eq!(entries[1].message, "Mod file is invalid");
+    }
+
+    #[test]
+    fn parses_legacy_lines() {
+        let entries = parse(&["2013-04-25 12:34:56 [SEVERE] Unable to launch"]);
+
+        assert_eq!(entries[0].time.as_deref(), Some("12:34:56"));
+        assert_eq!(entries[0].level, LogLevel::Error);
+        assert_eq!(entries[0].message, "Unable to launch");
+    }
+
+    #[test]
+    fn raw_lines_keep_previous_level() {
+        let entries = parse(&[
+            "[12:34:56] [main/ERROR]: Crashed",
+            "java.lang.NullPointerException",
+            "\tat abc.a(SourceFile:1)",
+        ]);
+
+        assert_eq!(entries.len(), 3);
+        assert!(entries.iter().all(|entry| entry.level == LogLevel::Error));
+        assert_eq!(entries[1].time, None);
+        assert_eq!(entries[2].message, "\tat abc.a(SourceFile:1)");
+    }
+
+    #[test]
+    fn stderr_is_never_buffered_as_event() {
+        let mut parser = LogParser::default();
+        let line = OutputLine {
+            stream: OutputStream::Stderr,
+            text: r#"<log4j:Event level="INFO">"#.to_owned(),
+        };
+
+        let entry = parser.push(&line).unwrap();
+        assert_eq!(entry.stream, OutputStream::Stderr);
+        assert_eq!(entry.message, r#"<log4j:Event level="INFO">"#);
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+
+/// `at dxo.a(SourceFile:123)`, the line number is optional
+static STACK_FRAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(\bat )([\w$.]+)\.([\w$<>]+)\(([^():]*)(?::(\d+))?\)").unwrap());
+
+/// `java.lang.IllegalStateException: message`, `Caused by: dxo$a: message`
+/// or `Exception in thread "main" dxo: message`
+static EXCEPTION_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^(\s*(?:Caused by: |Suppressed: |Exception in thread "[^"]*" )?)([\w$.]+)(:|$)"#)
+        .unwrap()
+});
+
+#[derive(Debug, Clone)]
+pub struct MethodMapping {
+    pub name: String,
+    /// Obfuscated line range of the method body, None when the mapping has no line info
+    pub lines: Option<(u32, u32)>,
+    /// Line range in the original source, None when it is the same as `lines`
+    pub original_lines: Option<(u32, u32)>,
+}
+
+impl MethodMapping {
+    /// Source line of an obfuscated `line` inside `lines`
+    pub fn original_line(&self, line: u32) -> Option<u32> {
+        let (start, end) = self.lines?;
+        if !(start..=end).contains(&line) {
+            return None;
+        }
+
+        Some(match self.original_lines {
+            // Both ranges usually have the same length, a single line covers the whole body
+            Some((original_start, original_end)) => {
+                (original_start + (line - start)).min(original_end)
+            }
+            None => line,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ClassMapping {
+    pub name: String,
+    /// Obfuscated names are overloaded, line numbers tell methods apart
+    pub methods: HashMap<String, Vec<MethodMapping>>,
+    pub fields: HashMap<String, String>,
+}
+
+impl ClassMapping {
+    /// Readable name of a method called at `line`
+    pub fn method_name(&self, obfuscated: &str, line: Option<u32>) -> Option<&str> {
+        let methods = self.methods.get(obfuscated)?;
+
+        let by_line = line.and_then(|line| self.method_at(obfuscated, line));
+
+        match by_line {
+            Some(method) => Some(&method.name),
+            // Without line info the name is only known when every overload has the same one
+            None if methods.iter().all(|m| m.name == methods[0].name) => Some(&methods[0].name),
+            None => None,
+        }
+    }
+
+    /// Line in the original source of a method called at `line`
+    pub fn original_line(&self, obfuscated: &str, line: u32) -> Option<u32> {
+        self.method_at(obfuscated, line)?.original_line(line)
+    }
+
+    /// Overload whose obfuscated line range contains `line`
+    fn method_at(&self, obfuscated: &str, line: u32) -> Option<&MethodMapping> {
+        self.methods
+            .get(obfuscated)?
+            .iter()
+            .find(|m| m.original_line(line).is_some())
+    }
+}
+
+/// Lookup tables of a ProGuard mapping file, keyed by obfuscated class name
+#[derive(Debug, Clone, Default)]
+pub struct ProguardMappings {
+    pub classes: HashMap<String, ClassMapping>,
+}
+
+impl ProguardMappings {
+    pub fn class_name<'a>(&'a self, obfuscated: &'a str) -> &'a str {
+        self.classes
+            .get(obfuscated)
+            .map(|class| class.name.as_str())
+            .unwrap_or(obfuscated)
+    }
+
+    /// Rewrite obfuscated stack frames and exception class names of a log or crash report
+    pub fn deobfuscate(&self, text: &str) -> String {
+        text.split('\n')
+            .map(|line| {
+                let line =
+                    STACK_FRAME.replace_all(line, |caps: &Captures| self.deobfuscate_frame(caps));
+                EXCEPTION_LINE
+                    .replace(&line, |caps: &Captures| {
+                        // Plain `word:` lines are usually log messages, not exceptions
+                        if caps[1].trim().is_empty() && !caps[2].contains('.') {
+                            return caps[0].to_owned();
+                        }
+                        format!("{}{}{}", &caps[1], self.class_name(&caps[2]), &caps[3])
+                    })
+                    .into_owned()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn deobfuscate_frame(&self, caps: &Captures) -> String {
+        let (prefix, class, method, source) = (&caps[1], &caps[2], &caps[3], &caps[4]);
+        let line = caps.get(5).and_then(|line| line.as_str().parse().ok());
+
+        let Some(mapping) = self.classes.get(class) else {
+            return caps[0].to_owned();
+        };
+
+        let name = mapping.method_name(method, line).unwrap_or(method);
+        match line {
+            Some(line) => match mapping.original_line(method, line) {
+                Some(line) => format!("{}{}.{}({}:{})", prefix, mapping.name, name, source, line),
+                // Obfuscated line numbers don't match the source, keep them marked as such
+                None => format!(
+                    "{}{}.{}({}, obfuscated line {})",
+                    prefix, mapping.name, name, source, line
+                ),
+            },
+            None => format!("{}{}.{}({})", prefix, mapping.name, name, source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mappings() -> ProguardMappings {
+        let class = |name: &str| ClassMapping {
+            name: name.to_owned(),
+            ..Default::default()
+        };
+
+        ProguardMappings {
+            classes: HashMap::from([
+                ("abc".to_owned(), class("net.minecraft.client.Minecraft")),
+                ("dxo$a".to_owned(), class("net.minecraft.world.Level$Error")),
+            ]),
+        }
+    }
+
+    #[test]
+    fn deobfuscates_exception_lines() {
+        let mappings = mappings();
+
+        assert_eq!(
+            mappings.deobfuscate("Caused by: dxo$a: broken"),
+            "Caused by: net.minecraft.world.Level$Error: broken"
+        );
+        assert_eq!(
+            mappings.deobfuscate(r#"Exception in thread "Render thread" abc: oops"#),
+            r#"Exception in thread "Render thread" net.minecraft.client.Minecraft: oops"#
+        );
+    }
+
+    #[test]
+    fn keeps_plain_log_messages() {
+        let mappings = mappings();
+
+        assert_eq!(mappings.deobfuscate("abc: something"), "abc: something");
+        assert_eq!(mappings.deobfuscate("abc"), "abc");
+        assert_eq!(
+            mappings.deobfuscate("java.lang.IllegalStateException: abc"),
+            "java.lang.IllegalStateException: abc"
+        );
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs;
+use std::path::Path;
+
+use crate::module::mapping::model::{ClassMapping, MethodMapping, ProguardMappings};
+
+pub struct MappingRepository {}
+
+impl MappingRepository {
+    pub fn read_mappings(path: &Path) -> Result<ProguardMappings, String> {
+        let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Ok(Self::parse(&content))
+    }
+
+    /// Parse ProGuard mappings
+    ///
+    /// ```text
+    /// net.minecraft.client.Minecraft -> fgo:
+    ///     int fps -> aa
+    ///     1:5:void run() -> e
+    ///     6:9:void tick():120:123 -> e
+    /// ```
+    pub fn parse(content: &str) -> ProguardMappings {
+        let mut mappings = ProguardMappings::default();
+        let mut current: Option<(String, ClassMapping)> = None;
+
+        for line in content.lines() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+
+            if !line.starts_with(char::is_whitespace) {
+                if let Some((obfuscated, class)) = current.take() {
+                    mappings.classes.insert(obfuscated, class);
+                }
+
+                current =
+                    line.trim_end_matches(':')
+                        .split_once(" -> ")
+                        .map(|(name, obfuscated)| {
+                            (
+                                obfuscated.to_owned(),
+                                ClassMapping {
+                                    name: name.to_owned(),
+                                    ..Default::default()
+                                },
+                            )
+                        });
+                continue;
+            }
+
+            let Some((_, class)) = &mut current else {
+                continue;
+            };
+            let Some((member, obfuscated)) = line.trim().split_once(" -> ") else {
+                continue;
+            };
+
+            match Self::parse_method(member) {
+                Some(method) => class
+                    .methods
+                    .entry(obfuscated.to_owned())
+                    .or_default()
+                    .push(method),
+                None => {
+                    // `type name`
+                    if let Some((_, name)) = member.rsplit_once(' ') {
+                        class.fields.insert(obfuscated.to_owned(), name.to_owned());
+                    }
+                }
+            }
+        }
+
+        if let Some((obfuscated, class)) = current {
+            mappings.classes.insert(obfuscated, class);
+        }
+
+        mappings
+    }
+
+    /// `[start:end:]type name(args)[:origStart[:origEnd]]`, None for fields
+    fn parse_method(member: &str) -> Option<MethodMapping> {
+        let (signature, rest) = member.split_once('(')?;
+
+        let mut parts = signature.splitn(3, ':');
+        let lines = match (parts.next(), parts.next(), parts.next()) {
+            (Some(start), Some(end), Some(_)) => start.parse().ok().zip(end.parse().ok()),
+            _ => None,
+        };
+
+        // A single original line covers the whole obfuscated range
+        let original_lines = rest
+            .rsplit_once(')')
+            .and_then(|(_, suffix)| suffix.strip_prefix(':'))
+            .and_then(|suffix| {
+                let (start, end) = suffix.split_once(':').unwrap_or((suffix, suffix));
+                start.parse().ok().zip(end.parse().ok())
+            });
+
+        let (_, name) = signature.rsplit_once(' ')?;
+
+        Some(MethodMapping {
+            name: name.to_owned(),
+            lines,
+            original_lines,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAPPINGS: &str = "\
+# {\"id\":\"sourceFile\",\"fileName\":\"Minecraft.java\"}
+net.minecraft.client.Minecraft -> fgo:
+    int fps -> aa
+    java.lang.String title -> ab
+    1:5:void run() -> e
+    6:9:void tick():120:123 -> e
+    10:10:void stop() -> f
+    11:14:void render(float):300 -> h
+    void close() -> g
+net.minecraft.client.Minecraft$Status -> fgo$a:
+    net.minecraft.client.Minecraft$Status READY -> a
+";
+
+    #[test]
+    fn parses_classes() {
+        let mappings = MappingRepository::parse(MAPPINGS);
+
+        assert_eq!(mappings.classes.len(), 2);
+        assert_eq!(mappings.class_name("fgo"), "net.minecraft.client.Minecraft");
+        assert_eq!(
+            mappings.class_name("fgo$a"),
+            "net.minecraft.client.Minecraft$Status"
+        );
+        assert_eq!(mappings.class_name("unknown"), "unknown");
+    }
+
+    #[test]
+    fn parses_fields() {
+        let mappings = MappingRepository::parse(MAPPINGS);
+        let class = &mappings.classes["fgo"];
+
+        assert_eq!(class.fields["aa"], "fps");
+        assert_eq!(class.fields["ab"], "title");
+        assert_eq!(mappings.classes["fgo$a"].fields["a"], "READY");
+    }
+
+    #[test]
+    fn parses_methods_with_line_ranges() {
+        let mappings = MappingRepository::parse(MAPPINGS);
+        let class = &mappings.classes["fgo"];
+
+        let overloads = &class.methods["e"];
+        assert_eq!(overloads.len(), 2);
+        assert_eq!(overloads[0].lines, Some((1, 5)));
+        assert_eq!(overloads[1].lines, Some((6, 9)));
+        assert_eq!(overloads[0].original_lines, None);
+        assert_eq!(overloads[1].original_lines, Some((120, 123)));
+        assert_eq!(class.methods["h"][0].original_lines, Some((300, 300)));
+        assert_eq!(class.methods["g"][0].lines, None);
+
+        // Overloads are told apart by the line of the frame
+        assert_eq!(class.method_name("e", Some(3)), Some("run"));
+        assert_eq!(class.method_name("e", Some(7)), Some("tick"));
+        assert_eq!(class.method_name("e", None), None);
+        assert_eq!(class.method_name("f", None), Some("stop"));
+        assert_eq!(class.method_name("g", Some(42)), Some("close"));
+    }
+
+    #[test]
+    fn deobfuscates_stack_frames() {
+        let mappings = MappingRepository::parse(MAPPINGS);
+
+        assert_eq!(
+            mappings.deobfuscate("\tat fgo.e(SourceFile:7)\n\tat fgo.g(Unknown Source)"),
+            "\tat net.minecraft.client.Minecraft.tick(SourceFile:121)\n\
+             \tat net.minecraft.client.Minecraft.close(Unknown Source)"
+        );
+    }
+
+    #[test]
+    fn maps_frame_lines_to_the_source() {
+        let mappings = MappingRepository::parse(MAPPINGS);
+
+        // Without original range the line is unchanged
+        assert_eq!(
+            mappings.deobfuscate("at fgo.e(SourceFile:3)"),
+            "at net.minecraft.client.Minecraft.run(SourceFile:3)"
+        );
+        assert_eq!(
+            mappings.deobfuscate("at fgo.h(SourceFile:13)"),
+            "at net.minecraft.client.Minecraft.render(SourceFile:300)"
+        );
+        assert_eq!(
+            mappings.deobfuscate("at fgo.g(SourceFile:42)"),
+            "at net.minecraft.client.Minecraft.close(SourceFile, obfuscated line 42)"
+        );
+    }
+}
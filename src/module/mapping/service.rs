@@ -0,0 +1,45 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::module::download::repository::DownloadRepository;
+use crate::module::launcher::service::LauncherService;
+use crate::module::mapping::model::ProguardMappings;
+use crate::module::mapping::repository::MappingRepository;
+
+/// File name of the mappings inside the version directory
+const MAPPINGS_FILE: &str = "client_mappings.txt";
+
+pub struct MappingService {}
+
+impl MappingService {
+    /// Load client mappings of an installed version, downloading them on first use
+    /// Only vanilla (official) names are mapped, mod loaders using other mappings are left as is
+    pub async fn load(version_id: &str, root_dir: &Path) -> Result<Arc<ProguardMappings>, String> {
+        let detail = LauncherService::load_version(version_id, root_dir).await?;
+
+        let info = detail
+            .downloads
+            .and_then(|downloads| downloads.client_mappings)
+            .ok_or_else(|| format!("{} has no client mappings", version_id))?;
+
+        let path = root_dir
+            .join("versions")
+            .join(version_id)
+            .join(MAPPINGS_FILE);
+        let sha1 = info.sha1.as_deref();
+
+        if !DownloadRepository::is_valid(&path, sha1, Some(info.size), false) {
+            DownloadRepository::download_file(&info.url, &path, sha1, Some(info.size)).await?;
+        }
+
+        // Mapping files are large (~10 MB)
+        let mappings = tokio::task::spawn_blocking(move || MappingRepository::read_mappings(&path))
+            .await
+            .map_err(|e| e.to_string())??;
+
+        Ok(Arc::new(mappings))
+    }
+}
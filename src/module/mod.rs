@@ -2,6 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod account;
+pub mod asset;
 pub mod config;
+pub mod crash;
+pub mod download;
+pub mod instance;
+pub mod java;
 pub mod launcher;
+pub mod loader;
+pub mod log;
+pub mod mapping;
+pub mod mods;
 pub mod mojang;
+pub mod process;
@@ -0,0 +1,477 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::module::mods::model::{DependencyKind, ModDependency, ModFormat, ProvidedMod};
+
+/// Metadata common to every mod format
+#[derive(Debug)]
+pub struct ModMetadata {
+    pub format: ModFormat,
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub description: String,
+    pub authors: Vec<String>,
+    /// Path of the icon inside the jar
+    pub icon: Option<String>,
+    pub dependencies: Vec<ModDependency>,
+    pub provides: Vec<ProvidedMod>,
+}
+
+/// String or list of strings
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl OneOrMany {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            Self::One(value) => vec![value],
+            Self::Many(values) => values,
+        }
+    }
+}
+
+/// Icon path, or paths keyed by size in pixels
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum Icon {
+    Path(String),
+    Sizes(HashMap<String, String>),
+}
+
+impl Icon {
+    /// Largest icon when several sizes are available
+    fn into_path(self) -> Option<String> {
+        match self {
+            Self::Path(path) => Some(path),
+            Self::Sizes(sizes) => sizes
+                .into_iter()
+                .max_by_key(|(size, _)| size.parse::<u32>().unwrap_or_default())
+                .map(|(_, path)| path),
+        }
+    }
+}
+
+/// `fabric.mod.json`
+#[derive(Deserialize, Debug)]
+pub struct FabricMod {
+    pub id: String,
+    #[serde(default)]
+    pub version: String,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub authors: Vec<FabricPerson>,
+    pub icon: Option<Icon>,
+    #[serde(default)]
+    pub depends: HashMap<String, OneOrMany>,
+    #[serde(default)]
+    pub recommends: HashMap<String, OneOrMany>,
+    #[serde(default)]
+    pub suggests: HashMap<String, OneOrMany>,
+    #[serde(default)]
+    pub breaks: HashMap<String, OneOrMany>,
+    #[serde(default)]
+    pub conflicts: HashMap<String, OneOrMany>,
+    /// Alias ids, they share the version of the mod
+    #[serde(default)]
+    pub provides: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum FabricPerson {
+    Name(String),
+    Contact { name: String },
+}
+
+impl From<FabricMod> for ModMetadata {
+    fn from(value: FabricMod) -> Self {
+        let mut dependencies = Vec::new();
+        for (entries, kind) in [
+            (value.depends, DependencyKind::Required),
+            (value.recommends, DependencyKind::Optional),
+            (value.suggests, DependencyKind::Optional),
+            (value.conflicts, DependencyKind::Discouraged),
+            (value.breaks, DependencyKind::Incompatible),
+        ] {
+            dependencies.extend(entries.into_iter().map(|(id, versions)| ModDependency {
+                id,
+                kind,
+                versions: versions.into_vec(),
+            }));
+        }
+        dependencies.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let provides = value
+            .provides
+            .into_iter()
+            .map(|id| ProvidedMod {
+                id,
+                version: value.version.clone(),
+            })
+            .collect();
+
+        Self {
+            format: ModFormat::Fabric,
+            name: value.name.unwrap_or_else(|| value.id.clone()),
+            id: value.id,
+            version: value.version,
+            description: value.description,
+            authors: value
+                .authors
+                .into_iter()
+                .map(|author| match author {
+                    FabricPerson::Name(name) | FabricPerson::Contact { name } => name,
+                })
+                .collect(),
+            icon: value.icon.and_then(Icon::into_path),
+            dependencies,
+            provides,
+        }
+    }
+}
+
+/// `quilt.mod.json`
+#[derive(Deserialize, Debug)]
+pub struct QuiltMod {
+    pub quilt_loader: QuiltLoader,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct QuiltLoader {
+    pub id: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub metadata: QuiltMetadata,
+    #[serde(default)]
+    pub depends: Vec<QuiltDependency>,
+    #[serde(default)]
+    pub breaks: Vec<QuiltDependency>,
+    #[serde(default)]
+    pub provides: Vec<QuiltProvides>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum QuiltProvides {
+    Id(String),
+    Object { id: String, version: Option<String> },
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct QuiltMetadata {
+    pub name: Option<String>,
+    pub description: String,
+    /// Contributor name mapped to their role
+    pub contributors: HashMap<String, String>,
+    pub icon: Option<Icon>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum QuiltDependency {
+    Id(String),
+    Object {
+        id: String,
+        #[serde(default)]
+        versions: Option<QuiltVersions>,
+        #[serde(default)]
+        optional: bool,
+    },
+}
+
+/// Version constraints of a Quilt dependency, `all` is treated like `any`
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum QuiltVersions {
+    Constraints(OneOrMany),
+    Any { any: Vec<String> },
+    All { all: Vec<String> },
+}
+
+impl QuiltDependency {
+    fn into_dependency(self, kind: DependencyKind) -> ModDependency {
+        let (id, versions, optional) = match self {
+            Self::Id(id) => (id, Vec::new(), false),
+            Self::Object {
+                id,
+                versions,
+                optional,
+            } => {
+                let versions = match versions {
+                    Some(QuiltVersions::Constraints(versions)) => versions.into_vec(),
+                    Some(QuiltVersions::Any { any: versions })
+                    | Some(QuiltVersions::All { all: versions }) => versions,
+                    None => Vec::new(),
+                };
+                (id, versions, optional)
+            }
+        };
+
+        // Ids may be prefixed with the Maven group, eg. "org.quiltmc:quilt_loader"
+        let id = match id.split_once(':') {
+            Some((_, id)) => id.to_owned(),
+            None => id,
+        };
+
+        ModDependency {
+            id,
+            kind: if optional && kind == DependencyKind::Required {
+                DependencyKind::Optional
+            } else {
+                kind
+            },
+            versions,
+        }
+    }
+}
+
+impl From<QuiltMod> for ModMetadata {
+    fn from(value: QuiltMod) -> Self {
+        let loader = value.quilt_loader;
+        let metadata = loader.metadata;
+
+        let mut authors: Vec<String> = metadata.contributors.into_keys().collect();
+        authors.sort();
+
+        let dependencies = loader
+            .depends
+            .into_iter()
+            .map(|d| d.into_dependency(DependencyKind::Required))
+            .chain(
+                loader
+                    .breaks
+                    .into_iter()
+                    .map(|d| d.into_dependency(DependencyKind::Incompatible)),
+            )
+            .collect();
+
+        let provides = loader
+            .provides
+            .into_iter()
+            .map(|provided| match provided {
+                QuiltProvides::Id(id) => ProvidedMod {
+                    id,
+                    version: loader.version.clone(),
+                },
+                QuiltProvides::Object { id, version } => ProvidedMod {
+                    id,
+                    version: version.unwrap_or_else(|| loader.version.clone()),
+                },
+            })
+            .collect();
+
+        Self {
+            format: ModFormat::Quilt,
+            name: metadata.name.unwrap_or_else(|| loader.id.clone()),
+            id: loader.id,
+            version: loader.version,
+            description: metadata.description,
+            authors,
+            icon: metadata.icon.and_then(Icon::into_path),
+            dependencies,
+            provides,
+        }
+    }
+}
+
+/// `META-INF/mods.toml` and `META-INF/neoforge.mods.toml`
+/// The first mod describes the jar, other mods of the jar are listed as provided
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ModsToml {
+    pub mods: Vec<ForgeMod>,
+    pub logo_file: Option<String>,
+    /// Dependencies keyed by mod id
+    #[serde(default)]
+    pub dependencies: HashMap<String, Vec<ForgeDependency>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ForgeMod {
+    pub mod_id: String,
+    /// `${file.jarVersion}` refers to `Implementation-Version` of the manifest
+    #[serde(default)]
+    pub version: String,
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub description: String,
+    pub authors: Option<String>,
+    pub logo_file: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ForgeDependency {
+    pub mod_id: String,
+    /// Forge only, replaced by `type` in NeoForge
+    pub mandatory: Option<bool>,
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub version_range: Option<String>,
+    /// `CLIENT`, `SERVER` or `BOTH` (default)
+    pub side: Option<String>,
+}
+
+impl ModsToml {
+    pub fn into_metadata(mut self, format: ModFormat) -> Option<ModMetadata> {
+        if self.mods.is_empty() {
+            return None;
+        }
+        let forge_mod = self.mods.remove(0);
+
+        let dependencies = self
+            .dependencies
+            .remove(&forge_mod.mod_id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|dependency| {
+                // Server only dependencies are never loaded by the client
+                if dependency
+                    .side
+                    .as_deref()
+                    .is_some_and(|side| side.eq_ignore_ascii_case("SERVER"))
+                {
+                    return None;
+                }
+
+                let kind = match dependency.kind.as_deref() {
+                    Some("required") => DependencyKind::Required,
+                    Some("optional") => DependencyKind::Optional,
+                    Some("discouraged") => DependencyKind::Discouraged,
+                    Some("incompatible") => DependencyKind::Incompatible,
+                    Some(_) => return None,
+                    None if dependency.mandatory == Some(false) => DependencyKind::Optional,
+                    None => DependencyKind::Required,
+                };
+
+                Some(ModDependency {
+                    id: dependency.mod_id,
+                    kind,
+                    versions: dependency.version_range.into_iter().collect(),
+                })
+            })
+            .collect();
+
+        Some(ModMetadata {
+            format,
+            name: forge_mod
+                .display_name
+                .unwrap_or_else(|| forge_mod.mod_id.clone()),
+            id: forge_mod.mod_id,
+            version: forge_mod.version,
+            description: forge_mod.description.trim().to_owned(),
+            authors: forge_mod
+                .authors
+                .map(|authors| split_authors(&authors))
+                .unwrap_or_default(),
+            icon: forge_mod.logo_file.or(self.logo_file),
+            dependencies,
+            provides: self
+                .mods
+                .into_iter()
+                .map(|other| ProvidedMod {
+                    id: other.mod_id,
+                    version: other.version,
+                })
+                .collect(),
+        })
+    }
+}
+
+/// `mcmod.info`, either a list of mods or a `modList` object
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum McModInfo {
+    List(Vec<McMod>),
+    Versioned {
+        #[serde(rename = "modList")]
+        mod_list: Vec<McMod>,
+    },
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default, rename_all = "camelCase")]
+pub struct McMod {
+    pub modid: String,
+    pub name: String,
+    pub version: String,
+    pub description: String,
+    pub author_list: Vec<String>,
+    pub authors: Vec<String>,
+    pub logo_file: String,
+    /// Entries like `Forge@[10.13,)` or `modid`
+    pub required_mods: Vec<String>,
+}
+
+impl McModInfo {
+    pub fn into_metadata(self) -> Option<ModMetadata> {
+        let mods = match self {
+            Self::List(mods) => mods,
+            Self::Versioned { mod_list } => mod_list,
+        };
+        let legacy_mod = mods.into_iter().next()?;
+
+        let dependencies = legacy_mod
+            .required_mods
+            .iter()
+            .map(|required| {
+                let (id, versions) = match required.split_once('@') {
+                    Some((id, range)) => (id, vec![range.to_owned()]),
+                    None => (required.as_str(), Vec::new()),
+                };
+
+                ModDependency {
+                    id: id.to_owned(),
+                    kind: DependencyKind::Required,
+                    versions,
+                }
+            })
+            .collect();
+
+        let authors = if legacy_mod.author_list.is_empty() {
+            legacy_mod.authors
+        } else {
+            legacy_mod.author_list
+        };
+
+        Some(ModMetadata {
+            format: ModFormat::LegacyForge,
+            name: if legacy_mod.name.is_empty() {
+                legacy_mod.modid.clone()
+            } else {
+                legacy_mod.name
+            },
+            id: legacy_mod.modid,
+            version: legacy_mod.version,
+            description: legacy_mod.description.trim().to_owned(),
+            authors,
+            icon: Some(legacy_mod.logo_file).filter(|logo| !logo.is_empty()),
+            dependencies,
+            provides: Vec::new(),
+        })
+    }
+}
+
+/// Forge authors are a single string, usually separated by commas
+fn split_authors(authors: &str) -> Vec<String> {
+    authors
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|author| !author.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
@@ -0,0 +1,8 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod metadata;
+pub mod model;
+pub mod repository;
+pub mod service;
+pub mod version;
@@ -0,0 +1,129 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt;
+use std::path::PathBuf;
+
+/// Suffix appended to the file name of disabled mods
+pub const DISABLED_SUFFIX: &str = ".disabled";
+
+/// Metadata file a mod was read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModFormat {
+    /// `fabric.mod.json`
+    Fabric,
+    /// `quilt.mod.json`
+    Quilt,
+    /// `META-INF/mods.toml`
+    Forge,
+    /// `META-INF/neoforge.mods.toml`
+    NeoForge,
+    /// `mcmod.info` of Forge before 1.13
+    LegacyForge,
+}
+
+impl fmt::Display for ModFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Fabric => "Fabric",
+            Self::Quilt => "Quilt",
+            Self::Forge => "Forge",
+            Self::NeoForge => "NeoForge",
+            Self::LegacyForge => "Legacy Forge",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyKind {
+    Required,
+    Optional,
+    /// Mod works badly together with the dependency
+    Discouraged,
+    /// Mod can't be loaded together with the dependency
+    Incompatible,
+}
+
+/// Dependency declared by a mod
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModDependency {
+    pub id: String,
+    pub kind: DependencyKind,
+    /// Version constraints as written by the mod, any of them matches
+    /// Fabric and Quilt use semver predicates (eg. ">=0.15"), Forge uses Maven ranges (eg. "[47,)")
+    pub versions: Vec<String>,
+}
+
+/// Mod jar of an instance
+#[derive(Debug, Clone)]
+pub struct ModInfo {
+    pub path: PathBuf,
+    pub enabled: bool,
+    /// None when the jar has no known metadata
+    pub format: Option<ModFormat>,
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub description: String,
+    pub authors: Vec<String>,
+    /// Content of the icon image inside the jar
+    pub icon: Option<Vec<u8>>,
+    pub dependencies: Vec<ModDependency>,
+    /// Other mod ids the jar provides, including mods bundled inside it
+    pub provides: Vec<ProvidedMod>,
+}
+
+/// Mod id provided by a jar beside its own, version is empty when unknown
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvidedMod {
+    pub id: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IssueSeverity {
+    Warning,
+    /// Game won't get past the mod loading screen
+    Error,
+}
+
+/// Problem found while checking mods of an instance before launch
+#[derive(Debug, Clone)]
+pub struct ModIssue {
+    pub severity: IssueSeverity,
+    pub message: String,
+}
+
+impl ModIssue {
+    pub fn error(message: String) -> Self {
+        Self {
+            severity: IssueSeverity::Error,
+            message,
+        }
+    }
+
+    pub fn warning(message: String) -> Self {
+        Self {
+            severity: IssueSeverity::Warning,
+            message,
+        }
+    }
+}
+
+impl ModInfo {
+    /// File name shown to the user, without the disabled suffix
+    pub fn file_name(&self) -> String {
+        let file_name = self
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        match file_name.strip_suffix(DISABLED_SUFFIX) {
+            Some(file_name) => file_name.to_owned(),
+            None => file_name,
+        }
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs;
+use std::io::{Cursor, Read, Seek};
+use std::path::{Path, PathBuf};
+
+use crate::module::mods::metadata::{FabricMod, McModInfo, ModMetadata, ModsToml, QuiltMod};
+use crate::module::mods::model::{DISABLED_SUFFIX, ModFormat, ModInfo, ProvidedMod};
+
+/// Placeholder of Forge mod versions taken from the jar manifest
+const JAR_VERSION: &str = "${file.jarVersion}";
+
+/// Directories of mods bundled inside a jar (jar-in-jar) for Fabric / Quilt and Forge / NeoForge
+const NESTED_JAR_DIRS: [&str; 2] = ["META-INF/jars/", "META-INF/jarjar/"];
+
+/// Bundled mods may bundle other mods, deeper levels are ignored
+const MAX_NESTED_DEPTH: usize = 3;
+
+pub struct ModRepository {}
+
+impl ModRepository {
+    /// Read every mod jar of `mods_dir`, enabled or not, sorted by name
+    pub fn list(mods_dir: &Path) -> Vec<ModInfo> {
+        let Ok(entries) = fs::read_dir(mods_dir) else {
+            return Vec::new();
+        };
+
+        let mut mods: Vec<ModInfo> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && Self::is_mod_file(path))
+            .map(|path| Self::read(&path))
+            .collect();
+
+        mods.sort_by_key(|info| info.name.to_lowercase());
+        mods
+    }
+
+    /// Read metadata of a mod jar
+    /// Jars without known metadata are still listed by their file name
+    pub fn read(path: &Path) -> ModInfo {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let mut info = ModInfo {
+            path: path.to_path_buf(),
+            enabled: !file_name.ends_with(DISABLED_SUFFIX),
+            format: None,
+            id: String::new(),
+            name: file_name
+                .trim_end_matches(DISABLED_SUFFIX)
+                .trim_end_matches(".jar")
+                .to_owned(),
+            version: String::new(),
+            description: String::new(),
+            authors: Vec::new(),
+            icon: None,
+            dependencies: Vec::new(),
+            provides: Vec::new(),
+        };
+
+        let Ok(file) = fs::File::open(path) else {
+            return info;
+        };
+        let Ok(mut archive) = zip::ZipArchive::new(file) else {
+            return info;
+        };
+        let Some(metadata) = Self::read_archive(&mut archive, 0) else {
+            return info;
+        };
+
+        info.icon = metadata
+            .icon
+            .as_deref()
+            .and_then(|icon| Self::read_entry(&mut archive, icon.trim_start_matches('/')).ok());
+        info.format = Some(metadata.format);
+        info.id = metadata.id;
+        info.name = metadata.name;
+        info.version = metadata.version;
+        info.description = metadata.description;
+        info.authors = metadata.authors;
+        info.dependencies = metadata.dependencies;
+        info.provides = metadata.provides;
+
+        info
+    }
+
+    /// Enable or disable a mod by renaming it, returns the new path
+    pub fn set_enabled(path: &Path, enabled: bool) -> Result<PathBuf, String> {
+        let path_str = path.to_string_lossy();
+        let new_path = match (enabled, path_str.strip_suffix(DISABLED_SUFFIX)) {
+            (true, Some(enabled_path)) => PathBuf::from(enabled_path),
+            (false, None) => PathBuf::from(format!("{}{}", path_str, DISABLED_SUFFIX)),
+            _ => return Ok(path.to_path_buf()),
+        };
+
+        if new_path.exists() {
+            return Err(format!("{} already exists", new_path.display()));
+        }
+
+        fs::rename(path, &new_path).map_err(|e| e.to_string())?;
+        Ok(new_path)
+    }
+
+    fn is_mod_file(path: &Path) -> bool {
+        let name = path.to_string_lossy();
+        name.ends_with(".jar") || name.ends_with(".jar.disabled")
+    }
+
+    /// Read metadata of a jar with the ids of the mods it bundles
+    fn read_archive<R: Read + Seek>(
+        archive: &mut zip::ZipArchive<R>,
+        depth: usize,
+    ) -> Option<ModMetadata> {
+        let mut metadata = Self::read_metadata(archive)?;
+
+        if metadata.version == JAR_VERSION
+            || metadata.provides.iter().any(|p| p.version == JAR_VERSION)
+        {
+            let jar_version = Self::read_jar_version(archive).unwrap_or_default();
+            for version in std::iter::once(&mut metadata.version)
+                .chain(metadata.provides.iter_mut().map(|p| &mut p.version))
+                .filter(|version| *version == JAR_VERSION)
+            {
+                version.clone_from(&jar_version);
+            }
+        }
+
+        if depth < MAX_NESTED_DEPTH {
+            let nested: Vec<String> = archive
+                .file_names()
+                .filter(|name| {
+                    name.ends_with(".jar")
+                        && NESTED_JAR_DIRS.iter().any(|dir| name.starts_with(dir))
+                })
+                .map(str::to_owned)
+                .collect();
+
+            for name in nested {
+                let Ok(content) = Self::read_entry(archive, &name) else {
+                    continue;
+                };
+                let Ok(mut nested_archive) = zip::ZipArchive::new(Cursor::new(content)) else {
+                    continue;
+                };
+                let Some(nested) = Self::read_archive(&mut nested_archive, depth + 1) else {
+                    continue;
+                };
+
+                metadata.provides.push(ProvidedMod {
+                    id: nested.id,
+                    version: nested.version,
+                });
+                metadata.provides.extend(nested.provides);
+            }
+        }
+
+        Some(metadata)
+    }
+
+    /// Jars supporting several loaders ship several metadata files
+    /// Fabric and Forge files are preferred, Quilt and NeoForge can load those mods too
+    fn read_metadata<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Option<ModMetadata> {
+        if let Ok(content) = Self::read_entry(archive, "fabric.mod.json")
+            && let Ok(fabric_mod) = serde_json::from_slice::<FabricMod>(&content)
+        {
+            return Some(fabric_mod.into());
+        }
+
+        if let Ok(content) = Self::read_entry(archive, "quilt.mod.json")
+            && let Ok(quilt_mod) = serde_json::from_slice::<QuiltMod>(&content)
+        {
+            return Some(quilt_mod.into());
+        }
+
+        for (name, format) in [
+            ("META-INF/mods.toml", ModFormat::Forge),
+            ("META-INF/neoforge.mods.toml", ModFormat::NeoForge),
+        ] {
+            if let Ok(content) = Self::read_entry(archive, name)
+                && let Ok(mods_toml) = toml::from_slice::<ModsToml>(&content)
+                && let Some(metadata) = mods_toml.into_metadata(format)
+            {
+                return Some(metadata);
+            }
+        }
+
+        let content = Self::read_entry(archive, "mcmod.info").ok()?;
+        serde_json::from_slice::<McModInfo>(&content)
+            .ok()?
+            .into_metadata()
+    }
+
+    /// `Implementation-Version` of the jar manifest
+    fn read_jar_version<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Option<String> {
+        let manifest = Self::read_entry(archive, "META-INF/MANIFEST.MF").ok()?;
+
+        String::from_utf8_lossy(&manifest)
+            .lines()
+            .find_map(|line| line.strip_prefix("Implementation-Version:"))
+            .map(|version| version.trim().to_owned())
+    }
+
+    fn read_entry<R: Read + Seek>(
+        archive: &mut zip::ZipArchive<R>,
+        name: &str,
+    ) -> Result<Vec<u8>, String> {
+        let mut entry = archive
+            .by_name(name)
+            .map_err(|e| format!("{}: {}", name, e))?;
+
+        let mut content = Vec::new();
+        entry.read_to_end(&mut content).map_err(|e| e.to_string())?;
+
+        Ok(content)
+    }
+}
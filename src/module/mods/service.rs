@@ -0,0 +1,281 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::module::instance::model::{Instance, ModLoader};
+use crate::module::mods::model::{DependencyKind, IssueSeverity, ModFormat, ModInfo, ModIssue};
+use crate::module::mods::repository::ModRepository;
+use crate::module::mods::version::{matches_predicate, matches_range};
+
+/// Mod id available at runtime with the mod providing it
+struct Provider<'a> {
+    name: &'a str,
+    /// Empty when unknown
+    version: &'a str,
+}
+
+/// Start of the error returned by `ensure_loadable`, lets the UI offer to launch anyway
+pub const UNLOADABLE_MODS: &str = "Mods can't be loaded";
+
+pub struct ModService {}
+
+impl ModService {
+    /// `mods` directory of an instance
+    pub fn mods_dir(instance: &Instance) -> PathBuf {
+        instance.path.join("mods")
+    }
+
+    /// Read every mod of an instance, jars are opened on a blocking thread
+    pub async fn list(instance: Instance) -> Result<Vec<ModInfo>, String> {
+        let mods_dir = Self::mods_dir(&instance);
+
+        tokio::task::spawn_blocking(move || ModRepository::list(&mods_dir))
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Enable or disable a mod, disabled jars are renamed to `.jar.disabled`
+    pub fn set_enabled(info: &mut ModInfo, enabled: bool) -> Result<(), String> {
+        info.path = ModRepository::set_enabled(&info.path, enabled)?;
+        info.enabled = enabled;
+
+        Ok(())
+    }
+
+    /// Fail when the game would crash while loading mods of the instance
+    pub async fn ensure_loadable(instance: &Instance) -> Result<(), String> {
+        if instance.loader == ModLoader::Vanilla {
+            return Ok(());
+        }
+
+        let mods = Self::list(instance.clone()).await?;
+        let errors: Vec<String> = Self::validate(&mods, instance)
+            .into_iter()
+            .filter(|issue| issue.severity == IssueSeverity::Error)
+            .map(|issue| format!("- {}", issue.message))
+            .collect();
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(format!(
+            "{} ({}):\n{}",
+            UNLOADABLE_MODS,
+            instance.name,
+            errors.join("\n")
+        ))
+    }
+
+    /// Check enabled mods against each other, the game version and the loader
+    /// Missing dependencies, version mismatches, duplicates and incompatibilities are reported, errors first
+    pub fn validate(mods: &[ModInfo], instance: &Instance) -> Vec<ModIssue> {
+        let formats = Self::supported_formats(instance.loader);
+        if formats.is_empty() {
+            return Vec::new();
+        }
+
+        let mut issues = Vec::new();
+        let mut loadable = Vec::new();
+
+        for info in mods.iter().filter(|info| info.enabled) {
+            match info.format {
+                Some(format) if !formats.contains(&format) => {
+                    issues.push(ModIssue::warning(format!(
+                        "{} is a {} mod and may not load with {}",
+                        info.name, format, instance.loader
+                    )));
+                }
+                Some(_) => loadable.push(info),
+                None => {}
+            }
+        }
+
+        // Ids are case insensitive for legacy Forge (eg. "Forge" and "forge")
+        let mut providers: HashMap<String, Vec<Provider>> = HashMap::new();
+        for (id, version) in Self::builtin_mods(instance) {
+            providers
+                .entry(id.to_owned())
+                .or_default()
+                .push(Provider { name: id, version });
+        }
+        for info in &loadable {
+            providers
+                .entry(info.id.to_lowercase())
+                .or_default()
+                .push(Provider {
+                    name: &info.name,
+                    version: &info.version,
+                });
+
+            for provided in &info.provides {
+                providers
+                    .entry(provided.id.to_lowercase())
+                    .or_default()
+                    .push(Provider {
+                        name: &info.name,
+                        version: &provided.version,
+                    });
+            }
+        }
+
+        // The same mod twice crashes every loader
+        let mut files: HashMap<&str, Vec<String>> = HashMap::new();
+        for info in &loadable {
+            files.entry(&info.id).or_default().push(info.file_name());
+        }
+        let mut duplicates: Vec<_> = files.into_iter().filter(|(_, f)| f.len() > 1).collect();
+        duplicates.sort();
+        for (id, files) in duplicates {
+            issues.push(ModIssue::error(format!(
+                "{} is installed more than once: {}",
+                id,
+                files.join(", ")
+            )));
+        }
+
+        for info in &loadable {
+            for dependency in &info.dependencies {
+                let id = dependency.id.to_lowercase();
+                if id == info.id.to_lowercase() {
+                    continue;
+                }
+
+                let found = providers.get(&id).map(Vec::as_slice).unwrap_or_default();
+                let matched = found.iter().map(|provider| {
+                    Self::matches(info.format, &dependency.versions, provider.version)
+                });
+                let any_match = matched.clone().any(|matches| matches != Some(false));
+                let definitely_match = matched.clone().any(|matches| matches == Some(true));
+
+                let versions = Self::describe_versions(&dependency.versions);
+                let installed = found
+                    .iter()
+                    .map(|provider| provider.version)
+                    .filter(|version| !version.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                match dependency.kind {
+                    DependencyKind::Required if found.is_empty() => {
+                        issues.push(ModIssue::error(format!(
+                            "{} requires {}{}, which is not installed",
+                            info.name, dependency.id, versions
+                        )));
+                    }
+                    DependencyKind::Required if !any_match => {
+                        issues.push(ModIssue::error(format!(
+                            "{} requires {}{}, but {} is installed",
+                            info.name, dependency.id, versions, installed
+                        )));
+                    }
+                    DependencyKind::Optional if !found.is_empty() && !any_match => {
+                        issues.push(ModIssue::warning(format!(
+                            "{} works with {}{}, but {} is installed",
+                            info.name, dependency.id, versions, installed
+                        )));
+                    }
+                    DependencyKind::Discouraged if definitely_match => {
+                        issues.push(ModIssue::warning(format!(
+                            "{} conflicts with {}",
+                            info.name, found[0].name
+                        )));
+                    }
+                    DependencyKind::Incompatible if definitely_match => {
+                        issues.push(ModIssue::error(format!(
+                            "{} is incompatible with {}{}",
+                            info.name, found[0].name, versions
+                        )));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        issues.sort_by_key(|issue| Reverse(issue.severity));
+        issues
+    }
+
+    /// Mod formats a loader can read, Quilt loads Fabric mods and NeoForge started as a Forge fork
+    fn supported_formats(loader: ModLoader) -> &'static [ModFormat] {
+        match loader {
+            ModLoader::Vanilla => &[],
+            ModLoader::Fabric => &[ModFormat::Fabric],
+            ModLoader::Quilt => &[ModFormat::Quilt, ModFormat::Fabric],
+            ModLoader::Forge => &[ModFormat::Forge, ModFormat::LegacyForge],
+            ModLoader::NeoForge => &[ModFormat::NeoForge, ModFormat::Forge],
+        }
+    }
+
+    /// Ids provided by the game and the loader, with their version when known
+    fn builtin_mods(instance: &Instance) -> Vec<(&'static str, &str)> {
+        let loader_version = instance.loader_version.as_deref().unwrap_or_default();
+
+        let mut builtins = vec![("minecraft", instance.version.as_str()), ("java", "")];
+        match instance.loader {
+            ModLoader::Vanilla => {}
+            ModLoader::Fabric => builtins.push(("fabricloader", loader_version)),
+            ModLoader::Quilt => {
+                builtins.push(("quilt_loader", loader_version));
+                builtins.push(("fabricloader", ""));
+            }
+            ModLoader::Forge => {
+                builtins.push(("forge", loader_version));
+                builtins.push(("fml", loader_version));
+                builtins.push(("mcp", ""));
+            }
+            ModLoader::NeoForge => {
+                builtins.push(("neoforge", loader_version));
+                builtins.push(("forge", ""));
+            }
+        }
+
+        builtins
+    }
+
+    /// Fabric and Quilt use semver predicates, Forge formats use Maven ranges
+    /// Returns None when the version is unknown or can't be compared
+    fn matches(format: Option<ModFormat>, versions: &[String], version: &str) -> Option<bool> {
+        if versions.is_empty() {
+            return Some(true);
+        }
+        if version.is_empty() {
+            return None;
+        }
+
+        match format {
+            Some(ModFormat::Fabric) | Some(ModFormat::Quilt) => {
+                let results: Vec<Option<bool>> = versions
+                    .iter()
+                    .map(|predicate| matches_predicate(predicate, version))
+                    .collect();
+
+                if results.contains(&Some(true)) {
+                    Some(true)
+                } else if results.contains(&None) {
+                    None
+                } else {
+                    Some(false)
+                }
+            }
+            _ => Some(versions.iter().any(|range| matches_range(range, version))),
+        }
+    }
+
+    fn describe_versions(versions: &[String]) -> String {
+        let versions: Vec<&str> = versions
+            .iter()
+            .map(String::as_str)
+            .filter(|version| !version.is_empty() && *version != "*")
+            .collect();
+
+        if versions.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", versions.join(" or "))
+        }
+    }
+}
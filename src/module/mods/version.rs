@@ -0,0 +1,314 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cmp::Ordering;
+
+/// Part of a version, versions are split on `.`, `-` and `_`
+#[derive(Debug, PartialEq, Eq)]
+enum Item<'a> {
+    Number(u64),
+    Qualifier(&'a str),
+}
+
+/// Order of Maven qualifiers, unknown qualifiers come after every known one
+fn qualifier_rank(qualifier: &str) -> usize {
+    match qualifier.to_ascii_lowercase().as_str() {
+        "alpha" | "a" => 0,
+        "beta" | "b" => 1,
+        "milestone" | "m" => 2,
+        "rc" | "cr" | "pre" => 3,
+        "snapshot" => 4,
+        "" | "ga" | "final" | "release" => 5,
+        "sp" => 6,
+        _ => 7,
+    }
+}
+
+fn items(version: &str) -> Vec<Item<'_>> {
+    // Build metadata never affects precedence
+    let version = version.split('+').next().unwrap_or_default();
+
+    version
+        .split(['.', '-', '_'])
+        .filter(|item| !item.is_empty())
+        .map(|item| match item.parse() {
+            Ok(number) => Item::Number(number),
+            Err(_) => Item::Qualifier(item),
+        })
+        .collect()
+}
+
+/// Compare versions item by item, numbers are greater than qualifiers
+/// so `1.0-beta` < `1.0` < `1.0.1`, missing numbers count as zero
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let a = items(a);
+    let b = items(b);
+
+    for i in 0..a.len().max(b.len()) {
+        let ordering = match (a.get(i), b.get(i)) {
+            (Some(Item::Number(a)), Some(Item::Number(b))) => a.cmp(b),
+            (Some(Item::Number(_)), Some(Item::Qualifier(_))) => Ordering::Greater,
+            (Some(Item::Qualifier(_)), Some(Item::Number(_))) => Ordering::Less,
+            (Some(Item::Qualifier(a)), Some(Item::Qualifier(b))) => qualifier_rank(a)
+                .cmp(&qualifier_rank(b))
+                .then_with(|| a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())),
+            (Some(Item::Number(a)), None) => a.cmp(&0),
+            (None, Some(Item::Number(b))) => 0.cmp(b),
+            (Some(Item::Qualifier(a)), None) => qualifier_rank(a).cmp(&qualifier_rank("")),
+            (None, Some(Item::Qualifier(b))) => qualifier_rank("").cmp(&qualifier_rank(b)),
+            (None, None) => Ordering::Equal,
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Semantic versions start with a number, eg. `1.20.1` or `0.15.0-beta.2`
+/// Minecraft snapshots (eg. `23w31a`) can't be ordered against releases
+fn is_semantic(version: &str) -> bool {
+    version.split(['-', '+']).next().is_some_and(|core| {
+        !core.is_empty()
+            && core
+                .split('.')
+                .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+    })
+}
+
+/// Check a version against a Fabric / Quilt predicate, eg. `>=1.20 <1.21`, `~0.15` or `1.20.x`
+/// Space separated predicates must all match
+/// Returns None when the version can't be compared
+pub fn matches_predicate(predicate: &str, version: &str) -> Option<bool> {
+    let predicate = predicate.trim();
+    if predicate.is_empty() || predicate == "*" {
+        return Some(true);
+    }
+    if predicate == version {
+        return Some(true);
+    }
+    if !is_semantic(version) {
+        return None;
+    }
+
+    for term in predicate.split_whitespace() {
+        if !matches_term(term, version)? {
+            return Some(false);
+        }
+    }
+
+    Some(true)
+}
+
+fn matches_term(term: &str, version: &str) -> Option<bool> {
+    let (operator, expected) = ["<=", ">=", "<", ">", "=", "~", "^"]
+        .into_iter()
+        .find_map(|operator| term.strip_prefix(operator).map(|rest| (operator, rest)))
+        .unwrap_or(("", term));
+    // Trailing `-` stands for the lowest pre-release of the version
+    let lowest_pre_release = expected.ends_with('-');
+    let expected = expected.trim_end_matches('-');
+
+    if expected == "*" {
+        return Some(true);
+    }
+    if !is_semantic(expected) && !expected.contains(['x', 'X', '*']) {
+        return None;
+    }
+
+    let mut ordering = compare_versions(version, expected);
+    // Pre-releases of the version itself sort above that lowest pre-release
+    let release = version.split(['-', '+']).next().unwrap_or_default();
+    if lowest_pre_release && ordering.is_lt() && compare_versions(release, expected).is_eq() {
+        ordering = Ordering::Greater;
+    }
+    let matches = match operator {
+        "<=" => ordering.is_le(),
+        ">=" => ordering.is_ge(),
+        "<" => ordering.is_lt(),
+        ">" => ordering.is_gt(),
+        // Same minor version
+        "~" => ordering.is_ge() && same_prefix(version, expected, 2),
+        // Same major version
+        "^" => ordering.is_ge() && same_prefix(version, expected, 1),
+        _ => match expected.find(['x', 'X', '*']) {
+            // Wildcard, eg. 1.20.x matches every 1.20 version
+            Some(wildcard) => {
+                let prefix = expected[..wildcard].trim_end_matches('.');
+                same_prefix(version, prefix, prefix.split('.').count())
+            }
+            None => ordering.is_eq(),
+        },
+    };
+
+    Some(matches)
+}
+
+/// Compare the first `count` numbers of both versions
+fn same_prefix(version: &str, expected: &str, count: usize) -> bool {
+    let numbers = |version: &str| -> Vec<u64> {
+        items(version)
+            .into_iter()
+            .take(count)
+            .map(|item| match item {
+                Item::Number(number) => number,
+                Item::Qualifier(_) => 0,
+            })
+            .collect()
+    };
+
+    let mut version = numbers(version);
+    let mut expected = numbers(expected);
+    version.resize(count, 0);
+    expected.resize(count, 0);
+
+    version == expected
+}
+
+/// Check a version against a Maven range, eg. `[47,)`, `[1.20.1,1.21)` or `(,2.0],[3.0]`
+/// A bare version is a recommendation and matches every version
+pub fn matches_range(range: &str, version: &str) -> bool {
+    let range = range.trim();
+    if range.is_empty() || range == "*" || !range.starts_with(['[', '(']) {
+        return true;
+    }
+
+    let mut rest = range;
+    while let Some(start) = rest.find(['[', '(']) {
+        let Some(end) = rest[start..].find([']', ')']) else {
+            break;
+        };
+        let end = start + end;
+
+        if matches_bounds(&rest[start..=end], version) {
+            return true;
+        }
+        rest = &rest[end + 1..];
+    }
+
+    false
+}
+
+/// Single range with its brackets, eg. `[1.0,2.0)` or `[1.0]`
+fn matches_bounds(bounds: &str, version: &str) -> bool {
+    let inclusive_min = bounds.starts_with('[');
+    let inclusive_max = bounds.ends_with(']');
+    let inner = &bounds[1..bounds.len() - 1];
+
+    let Some((min, max)) = inner.split_once(',') else {
+        // [1.0] only matches the version itself
+        return compare_versions(version, inner.trim()).is_eq();
+    };
+
+    let (min, max) = (min.trim(), max.trim());
+    let above_min = min.is_empty() || {
+        let ordering = compare_versions(version, min);
+        ordering.is_gt() || (inclusive_min && ordering.is_eq())
+    };
+    let below_max = max.is_empty() || {
+        let ordering = compare_versions(version, max);
+        ordering.is_lt() || (inclusive_max && ordering.is_eq())
+    };
+
+    above_min && below_max
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_versions() {
+        let cases = [
+            ("1.0", "1.0.0", Ordering::Equal),
+            ("1.0.1", "1.0", Ordering::Greater),
+            ("1.0-beta", "1.0", Ordering::Less),
+            ("1.0-alpha", "1.0-beta", Ordering::Less),
+            ("1.0-rc1", "1.0-beta", Ordering::Greater),
+            ("1.0-SNAPSHOT", "1.0", Ordering::Less),
+            ("1.10", "1.9", Ordering::Greater),
+            ("0.15.0+build.1", "0.15.0+build.2", Ordering::Equal),
+            ("47.2.0", "47.10.0", Ordering::Less),
+        ];
+
+        for (a, b, expected) in cases {
+            assert_eq!(compare_versions(a, b), expected, "{} vs {}", a, b);
+        }
+    }
+
+    #[test]
+    fn matches_predicates() {
+        let cases = [
+            ("*", "1.20.1", Some(true)),
+            ("", "1.20.1", Some(true)),
+            (">=1.20", "1.20.1", Some(true)),
+            (">=1.20", "1.19.4", Some(false)),
+            (">=1.20 <1.21", "1.20.6", Some(true)),
+            (">=1.20 <1.21", "1.21", Some(false)),
+            ("<=0.15", "0.15.0", Some(true)),
+            (">0.15", "0.15.0", Some(false)),
+            ("=1.20.1", "1.20.1", Some(true)),
+            ("1.20.1", "1.20.2", Some(false)),
+            ("~0.15", "0.15.11", Some(true)),
+            ("~0.15", "0.16.0", Some(false)),
+            ("^1.2", "1.9.0", Some(true)),
+            ("^1.2", "2.0.0", Some(false)),
+            ("^1.2", "1.1.0", Some(false)),
+            ("1.20.x", "1.20.4", Some(true)),
+            ("1.20.x", "1.21", Some(false)),
+            (">=0.15.0-", "0.15.0-beta.2", Some(true)),
+            ("<0.15.0-", "0.15.0-beta.2", Some(false)),
+            (">=0.15.0-", "0.14.9", Some(false)),
+            (">=0.14.0-", "0.15.0-beta.2", Some(true)),
+            // Snapshots and free-form versions can't be ordered
+            (">=1.20", "23w31a", None),
+            (">=1.20-pre", "1.20.1", Some(true)),
+            (">=b1.7.3", "1.20.1", None),
+            ("23w31a", "23w31a", Some(true)),
+        ];
+
+        for (predicate, version, expected) in cases {
+            assert_eq!(
+                matches_predicate(predicate, version),
+                expected,
+                "{:?} against {}",
+                predicate,
+                version
+            );
+        }
+    }
+
+    #[test]
+    fn matches_ranges() {
+        let cases = [
+            ("[47,)", "47.2.0", true),
+            ("[47,)", "46.0.1", false),
+            ("[1.20.1,1.21)", "1.20.1", true),
+            ("[1.20.1,1.21)", "1.20.6", true),
+            ("[1.20.1,1.21)", "1.21", false),
+            ("(1.0,2.0]", "1.0", false),
+            ("(1.0,2.0]", "2.0", true),
+            ("[1.0]", "1.0.0", true),
+            ("[1.0]", "1.0.1", false),
+            ("(,2.0],[3.0,)", "2.5", false),
+            ("(,2.0],[3.0,)", "3.1", true),
+            ("(,2.0],[3.0,)", "1.0", true),
+            // A bare version is only a recommendation
+            ("1.0", "2.0", true),
+            ("*", "2.0", true),
+            ("", "2.0", true),
+        ];
+
+        for (range, version, expected) in cases {
+            assert_eq!(
+                matches_range(range, version),
+                expected,
+                "{:?} against {}",
+                range,
+                version
+            );
+        }
+    }
+}
@@ -7,7 +7,7 @@ pub struct MojangRepository;
 
 impl MojangRepository {
     pub fn new() -> Self {
-        return Self {};
+        Self {}
     }
 
     pub async fn get_all_versions(&self) -> Result<Vec<MinecraftVersion>, String> {
@@ -0,0 +1,86 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Maximum number of output lines kept for each process
+pub const MAX_OUTPUT_LINES: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Debug, Clone)]
+pub struct OutputLine {
+    pub stream: OutputStream,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProcessStatus {
+    /// Game files are being prepared, process is not started yet
+    Launching,
+    Running {
+        pid: Option<u32>,
+    },
+    Exited {
+        /// None when the process was terminated by a signal
+        code: Option<i32>,
+        duration: Duration,
+        killed: bool,
+    },
+}
+
+impl ProcessStatus {
+    pub fn is_active(&self) -> bool {
+        !matches!(self, Self::Exited { .. })
+    }
+}
+
+/// Snapshot of a game process published to the UI
+#[derive(Debug, Clone)]
+pub struct ProcessState {
+    pub status: ProcessStatus,
+    /// Number of output lines received since start, including dropped ones
+    pub output_lines: usize,
+}
+
+impl Default for ProcessState {
+    fn default() -> Self {
+        Self {
+            status: ProcessStatus::Launching,
+            output_lines: 0,
+        }
+    }
+}
+
+/// Keeps the last `MAX_OUTPUT_LINES` lines of a process
+#[derive(Debug, Default)]
+pub struct OutputBuffer {
+    lines: VecDeque<OutputLine>,
+    total: usize,
+}
+
+impl OutputBuffer {
+    pub fn push(&mut self, line: OutputLine) -> usize {
+        if self.lines.len() == MAX_OUTPUT_LINES {
+            self.lines.pop_front();
+        }
+
+        self.lines.push_back(line);
+        self.total += 1;
+        self.total
+    }
+
+    /// Returns lines received after the first `seen` lines with the total number of lines
+    /// Lines already dropped from the buffer are skipped
+    pub fn lines_since(&self, seen: usize) -> (usize, Vec<OutputLine>) {
+        let first = self.total - self.lines.len();
+        let skip = seen.saturating_sub(first);
+
+        (self.total, self.lines.iter().skip(skip).cloned().collect())
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures_util::Stream;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Command;
+use tokio::sync::{oneshot, watch};
+
+use crate::module::process::entity::{
+    OutputBuffer, OutputLine, OutputStream, ProcessState, ProcessStatus,
+};
+
+/// Time left to readers to flush the last lines once the game has exited
+/// Processes started by the game may keep the pipes open much longer
+const OUTPUT_FLUSH_TIMEOUT: Duration = Duration::from_secs(2);
+
+// Internal struct
+struct ProcessHandle {
+    state: watch::Sender<ProcessState>,
+    output: Arc<Mutex<OutputBuffer>>,
+    kill: Option<oneshot::Sender<()>>,
+}
+
+/// Registry of game processes started by the launcher, keyed by instance id
+pub struct ProcessRepository {
+    processes: Mutex<HashMap<String, ProcessHandle>>,
+}
+
+impl ProcessRepository {
+    pub fn new() -> Self {
+        Self {
+            processes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reserve an instance before its files are prepared
+    /// Fails if the instance is already launching or running
+    pub fn reserve(&self, id: &str) -> Result<(), String> {
+        let mut lock = self.processes.lock().unwrap();
+
+        if let Some(handle) = lock.get(id)
+            && handle.state.borrow().status.is_active()
+        {
+            return Err(format!("{} is already running", id));
+        }
+
+        lock.insert(
+            id.to_owned(),
+            ProcessHandle {
+                state: watch::Sender::new(ProcessState::default()),
+                output: Arc::new(Mutex::new(OutputBuffer::default())),
+                kill: None,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Remove a reservation when launch failed before the game started
+    pub fn release(&self, id: &str) {
+        let mut lock = self.processes.lock().unwrap();
+
+        if lock
+            .get(id)
+            .is_some_and(|handle| handle.state.borrow().status == ProcessStatus::Launching)
+        {
+            lock.remove(id);
+        }
+    }
+
+    /// Start the game of a reserved instance
+    /// stdout and stderr are captured, `on_exit` runs once the process has exited
+    pub fn spawn(
+        &self,
+        id: &str,
+        mut cmd: Command,
+        on_exit: impl FnOnce() + Send + 'static,
+    ) -> Result<(), String> {
+        let mut lock = self.processes.lock().unwrap();
+        let handle = lock
+            .get_mut(id)
+            .ok_or_else(|| format!("{} is not reserved", id))?;
+
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        // Own process group, so killing the game also stops a wrapper command in front of it
+        #[cfg(unix)]
+        cmd.process_group(0);
+
+        let mut child = cmd.spawn().map_err(|e| format!("Spawn error: {}", e))?;
+        let started_at = Instant::now();
+
+        let (kill_sender, kill_receiver) = oneshot::channel();
+        handle.kill = Some(kill_sender);
+        handle
+            .state
+            .send_modify(|s| s.status = ProcessStatus::Running { pid: child.id() });
+
+        let stdout = child.stdout.take().map(|stdout| {
+            tokio::spawn(Self::capture(
+                stdout,
+                OutputStream::Stdout,
+                handle.output.clone(),
+                handle.state.clone(),
+            ))
+        });
+        let stderr = child.stderr.take().map(|stderr| {
+            tokio::spawn(Self::capture(
+                stderr,
+                OutputStream::Stderr,
+                handle.output.clone(),
+                handle.state.clone(),
+            ))
+        });
+
+        let state = handle.state.clone();
+        tokio::spawn(async move {
+            let (status, killed) = tokio::select! {
+                status = child.wait() => (status, false),
+                Ok(()) = kill_receiver => {
+                    #[cfg(unix)]
+                    if let Some(pid) = child.id() {
+                        Self::kill_group(pid);
+                    }
+                    let _ = child.kill().await;
+                    (child.wait().await, true)
+                }
+            };
+
+            // Let readers flush the last lines before reporting exit
+            let readers = async {
+                for reader in [stdout, stderr].into_iter().flatten() {
+                    let _ = reader.await;
+                }
+            };
+            let _ = tokio::time::timeout(OUTPUT_FLUSH_TIMEOUT, readers).await;
+
+            on_exit();
+
+            state.send_modify(|s| {
+                s.status = ProcessStatus::Exited {
+                    code: status.ok().and_then(|status| status.code()),
+                    duration: started_at.elapsed(),
+                    killed,
+                }
+            });
+        });
+
+        Ok(())
+    }
+
+    /// Ask a running game to stop
+    pub fn kill(&self, id: &str) {
+        let mut lock = self.processes.lock().unwrap();
+
+        if let Some(kill) = lock.get_mut(id).and_then(|handle| handle.kill.take()) {
+            let _ = kill.send(());
+        }
+    }
+
+    pub fn state(&self, id: &str) -> Option<ProcessState> {
+        let lock = self.processes.lock().unwrap();
+        lock.get(id).map(|handle| handle.state.borrow().clone())
+    }
+
+    /// Returns output lines received after the first `seen` lines
+    /// with the number of lines read so far, to pass as `seen` next time
+    pub fn output_since(&self, id: &str, seen: usize) -> (usize, Vec<OutputLine>) {
+        let lock = self.processes.lock().unwrap();
+        lock.get(id)
+            .map(|handle| handle.output.lock().unwrap().lines_since(seen))
+            .unwrap_or((seen, Vec::new()))
+    }
+
+    /// Stream of state changes of an instance, ends once the process has exited
+    pub fn state_stream(&self, id: &str) -> impl Stream<Item = ProcessState> + use<> {
+        let receiver = {
+            let lock = self.processes.lock().unwrap();
+            lock.get(id).map(|handle| {
+                let mut receiver = handle.state.subscribe();
+                // Publish current state first
+                receiver.mark_changed();
+                receiver
+            })
+        };
+
+        futures_util::stream::unfold(receiver, |receiver| async move {
+            let mut receiver = receiver?;
+            receiver.changed().await.ok()?;
+            let state = receiver.borrow_and_update().clone();
+
+            let next = state.status.is_active().then_some(receiver);
+            Some((state, next))
+        })
+    }
+
+    /// Kill every process of the group started by `spawn`
+    #[cfg(unix)]
+    fn kill_group(pid: u32) {
+        // SAFETY: only sends a signal, a negative pid targets the process group
+        unsafe {
+            libc::kill(-(pid as libc::pid_t), libc::SIGKILL);
+        }
+    }
+
+    /// Read output line by line, invalid UTF-8 is replaced
+    async fn capture(
+        reader: impl AsyncRead + Unpin,
+        stream: OutputStream,
+        output: Arc<Mutex<OutputBuffer>>,
+        state: watch::Sender<ProcessState>,
+    ) {
+        let mut reader = BufReader::new(reader);
+        let mut buffer = Vec::new();
+
+        loop {
+            buffer.clear();
+            match reader.read_until(b'\n', &mut buffer).await {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+
+            let text = String::from_utf8_lossy(&buffer)
+                .trim_end_matches(['\r', '\n'])
+                .to_owned();
+
+            let total = output.lock().unwrap().push(OutputLine { stream, text });
+            state.send_modify(|s| s.output_lines = total);
+        }
+    }
+}
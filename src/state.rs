@@ -14,24 +14,31 @@ pub enum Page {
     Instances,
     Settings,
     Accounts,
+    Console,
 }
 
 pub struct SavedScreen {
     pub home: ui::home::HomeScreen,
+    pub instances: ui::instances::InstancesScreen,
     pub settings: ui::settings::SettingsScreen,
     pub accounts: ui::accounts::AccountsScreen,
+    pub console: ui::console::ConsoleScreen,
 }
 
 impl SavedScreen {
     pub fn new(
         home: ui::home::HomeScreen,
+        instances: ui::instances::InstancesScreen,
         settings: ui::settings::SettingsScreen,
         accounts: ui::accounts::AccountsScreen,
+        console: ui::console::ConsoleScreen,
     ) -> Self {
         Self {
             home,
+            instances,
             settings,
             accounts,
+            console,
         }
     }
 }
@@ -54,7 +61,9 @@ impl State {
 
 pub enum Message {
     Home(ui::home::Message),
+    Instances(ui::instances::Message),
     Settings(ui::settings::Message),
     Accounts(ui::accounts::Message),
+    Console(ui::console::Message),
     Sidebar(sidebar::Message),
 }
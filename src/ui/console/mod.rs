@@ -0,0 +1,368 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use iced::widget::{
+    Space, button, column, container, operation, pick_list, row, scrollable, text, text_input,
+    toggler,
+};
+use iced::{Alignment, Background, Border, Element, Font, Length, Task, Theme};
+
+use crate::module::launcher::service::LauncherService;
+use crate::module::log::model::{LogEntry, LogLevel};
+use crate::module::log::parser::LogParser;
+use crate::module::mapping::model::ProguardMappings;
+use crate::module::mapping::service::MappingService;
+use crate::module::process::entity::{OutputStream, ProcessState, ProcessStatus};
+use crate::module::process::repository::ProcessRepository;
+
+/// Entries kept in memory, older ones are dropped
+const MAX_ENTRIES: usize = 10_000;
+
+/// Entries rendered at once, search to find older ones
+const MAX_VISIBLE_ENTRIES: usize = 1_000;
+
+const LOG_SCROLLABLE: &str = "console-log";
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    ProcessStateChanged(String, ProcessState),
+    LevelSelected(LogLevel),
+    SearchChanged(String),
+    AutoScrollToggled(bool),
+    Copy,
+    Save,
+    Saved(Result<(), String>),
+    Clear,
+    Deobfuscate,
+    MappingsLoaded(String, Result<Arc<ProguardMappings>, String>),
+}
+
+pub struct ConsoleScreen {
+    process_repo: Arc<ProcessRepository>,
+
+    /// Instance whose output is shown
+    instance: Option<String>,
+    /// Version launched by the instance, mappings are loaded for it
+    version_id: Option<String>,
+    status: Option<ProcessStatus>,
+    /// Output lines already read from the process
+    seen_lines: usize,
+    parser: LogParser,
+    entries: VecDeque<LogEntry>,
+
+    min_level: LogLevel,
+    search: String,
+    auto_scroll: bool,
+    notice: Option<String>,
+    /// Mappings of the instance once deobfuscation is turned on
+    mappings: Option<Arc<ProguardMappings>>,
+    loading_mappings: bool,
+}
+
+impl ConsoleScreen {
+    pub fn new(process_repo: Arc<ProcessRepository>) -> Self {
+        Self {
+            process_repo,
+            instance: None,
+            version_id: None,
+            status: None,
+            seen_lines: 0,
+            parser: LogParser::default(),
+            entries: VecDeque::new(),
+            min_level: LogLevel::Trace,
+            search: String::new(),
+            auto_scroll: true,
+            notice: None,
+            mappings: None,
+            loading_mappings: false,
+        }
+    }
+
+    /// Follow output of a freshly launched instance running `version_id`
+    pub fn attach(&mut self, id: &str, version_id: &str) -> Task<Message> {
+        self.instance = Some(id.to_owned());
+        self.version_id = Some(version_id.to_owned());
+        self.status = None;
+        self.seen_lines = 0;
+        self.parser = LogParser::default();
+        self.entries.clear();
+        self.notice = None;
+        self.mappings = None;
+
+        let id = id.to_owned();
+        Task::run(self.process_repo.state_stream(&id), move |state| {
+            Message::ProcessStateChanged(id.clone(), state)
+        })
+    }
+
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::ProcessStateChanged(id, state) => {
+                if self.instance.as_ref() != Some(&id) {
+                    return Task::none();
+                }
+
+                // State may be older than the buffer, count the lines actually read
+                let (total, lines) = self.process_repo.output_since(&id, self.seen_lines);
+                for line in lines {
+                    if let Some(mut entry) = self.parser.push(&line) {
+                        if let Some(mappings) = &self.mappings {
+                            Self::deobfuscate_entry(mappings, &mut entry);
+                        }
+                        if self.entries.len() == MAX_ENTRIES {
+                            self.entries.pop_front();
+                        }
+                        self.entries.push_back(entry);
+                    }
+                }
+                self.seen_lines = total;
+                self.status = Some(state.status);
+
+                if self.auto_scroll {
+                    return operation::snap_to_end(LOG_SCROLLABLE);
+                }
+            }
+            Message::LevelSelected(level) => self.min_level = level,
+            Message::SearchChanged(search) => self.search = search,
+            Message::AutoScrollToggled(enabled) => {
+                self.auto_scroll = enabled;
+                if enabled {
+                    return operation::snap_to_end(LOG_SCROLLABLE);
+                }
+            }
+            Message::Copy => {
+                self.notice = Some("Copied to clipboard".to_owned());
+                return iced::clipboard::write(self.filtered_text());
+            }
+            Message::Save => {
+                let content = self.filtered_text();
+
+                return Task::perform(
+                    async move {
+                        let Some(handle) = rfd::AsyncFileDialog::new()
+                            .set_title("Save Game Log")
+                            .set_file_name("latest.log")
+                            .save_file()
+                            .await
+                        else {
+                            return Ok(());
+                        };
+
+                        tokio::fs::write(handle.path(), content)
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                    Message::Saved,
+                );
+            }
+            Message::Saved(result) => {
+                self.notice = Some(match result {
+                    Ok(()) => "Log saved".to_owned(),
+                    Err(e) => format!("Failed to save log: {}", e),
+                });
+            }
+            Message::Clear => self.entries.clear(),
+            Message::Deobfuscate => {
+                let (Some(id), Some(version_id)) = (self.instance.clone(), self.version_id.clone())
+                else {
+                    return Task::none();
+                };
+
+                self.loading_mappings = true;
+                self.notice = Some("Loading mappings...".to_owned());
+
+                return Task::perform(
+                    async move {
+                        let root_dir =
+                            LauncherService::get_minecraft_root_dir().map_err(|e| e.to_string())?;
+                        MappingService::load(&version_id, &root_dir).await
+                    },
+                    move |result| Message::MappingsLoaded(id.clone(), result),
+                );
+            }
+            Message::MappingsLoaded(id, result) => {
+                self.loading_mappings = false;
+
+                // Another instance was attached in the meantime
+                if self.instance.as_ref() != Some(&id) {
+                    return Task::none();
+                }
+
+                match result {
+                    Ok(mappings) => {
+                        for entry in &mut self.entries {
+                            Self::deobfuscate_entry(&mappings, entry);
+                        }
+                        self.mappings = Some(mappings);
+                        self.notice = Some("Deobfuscated with Mojang mappings".to_owned());
+                    }
+                    Err(e) => self.notice = Some(format!("Failed to load mappings: {}", e)),
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    fn deobfuscate_entry(mappings: &ProguardMappings, entry: &mut LogEntry) {
+        entry.message = mappings.deobfuscate(&entry.message);
+        if let Some(throwable) = &mut entry.throwable {
+            *throwable = mappings.deobfuscate(throwable);
+        }
+    }
+
+    fn filtered_entries(&self) -> impl Iterator<Item = &LogEntry> {
+        let query = self.search.to_lowercase();
+
+        self.entries
+            .iter()
+            .filter(move |entry| entry.level >= self.min_level && entry.matches(&query))
+    }
+
+    fn filtered_text(&self) -> String {
+        self.filtered_entries()
+            .map(|entry| entry.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let status = match &self.status {
+            None if self.instance.is_none() => "Launch a game to see its output".to_owned(),
+            None | Some(ProcessStatus::Launching) => "Launching".to_owned(),
+            Some(ProcessStatus::Running { .. }) => "Running".to_owned(),
+            Some(ProcessStatus::Exited {
+                code: Some(code), ..
+            }) => format!("Exited with code {}", code),
+            Some(ProcessStatus::Exited { .. }) => "Stopped".to_owned(),
+        };
+
+        let header = row![
+            column![
+                text("Console").size(28).font(Font {
+                    weight: iced::font::Weight::Bold,
+                    ..Default::default()
+                }),
+                text(match &self.instance {
+                    Some(id) => format!("{} - {}", id, status),
+                    None => status,
+                })
+                .size(14),
+            ]
+            .spacing(5),
+            Space::new().width(Length::Fill),
+            self.notice.as_deref().map(|notice| text(notice).size(12)),
+        ]
+        .align_y(Alignment::Center);
+
+        let toolbar = row![
+            pick_list(LogLevel::ALL, Some(self.min_level), Message::LevelSelected)
+                .width(Length::Fixed(110.0)),
+            text_input("Search...", &self.search)
+                .on_input(Message::SearchChanged)
+                .padding(8)
+                .width(Length::Fill),
+            toggler(self.auto_scroll)
+                .label("Auto-scroll")
+                .on_toggle(Message::AutoScrollToggled),
+            button(text("Copy").size(14))
+                .on_press(Message::Copy)
+                .style(button::secondary),
+            button(text("Save").size(14))
+                .on_press(Message::Save)
+                .style(button::secondary),
+            button(text("Deobfuscate").size(14))
+                .on_press_maybe(
+                    (self.instance.is_some() && self.mappings.is_none() && !self.loading_mappings)
+                        .then_some(Message::Deobfuscate)
+                )
+                .style(button::secondary),
+            button(text("Clear").size(14))
+                .on_press(Message::Clear)
+                .style(button::text),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let entries: Vec<&LogEntry> = self.filtered_entries().collect();
+        let hidden = entries.len().saturating_sub(MAX_VISIBLE_ENTRIES);
+
+        let lines = column(
+            entries[hidden..]
+                .iter()
+                .map(|entry| Self::view_entry(entry)),
+        )
+        .spacing(2);
+
+        let log = scrollable(
+            column![
+                (hidden > 0).then(|| {
+                    text(format!(
+                        "{} older lines hidden, use search to find them",
+                        hidden
+                    ))
+                    .size(12)
+                }),
+                lines,
+            ]
+            .spacing(6)
+            .padding(12)
+            .width(Length::Fill),
+        )
+        .id(LOG_SCROLLABLE)
+        .height(Length::Fill)
+        .width(Length::Fill);
+
+        column![
+            header,
+            Space::new().height(10),
+            toolbar,
+            container(log)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .style(|theme: &Theme| {
+                    let palette = theme.extended_palette();
+                    container::Style {
+                        background: Some(Background::Color(palette.background.weak.color)),
+                        border: Border {
+                            width: 1.0,
+                            color: palette.background.strong.color,
+                            radius: 12.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                }),
+        ]
+        .spacing(10)
+        .padding(30)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn view_entry(entry: &LogEntry) -> Element<'_, Message> {
+        let level = entry.level;
+        let stderr = entry.stream == OutputStream::Stderr;
+
+        text(entry.to_string())
+            .size(12)
+            .font(Font::MONOSPACE)
+            .style(move |theme: &Theme| {
+                let palette = theme.extended_palette();
+                text::Style {
+                    color: match level {
+                        LogLevel::Trace | LogLevel::Debug => Some(palette.secondary.base.color),
+                        // Plain stderr output is usually an error printed outside log4j
+                        LogLevel::Info if stderr => Some(palette.danger.base.color),
+                        LogLevel::Info => None,
+                        LogLevel::Warn => Some(palette.warning.base.color),
+                        LogLevel::Error | LogLevel::Fatal => Some(palette.danger.base.color),
+                    },
+                }
+            })
+            .into()
+    }
+}
@@ -1,22 +1,42 @@
 // Copyright 2026 MineHighVN, AXLauncher contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
-use iced::widget::{Space, button, column, container, row, scrollable, text};
+use iced::widget::{Space, button, column, container, progress_bar, row, scrollable, text};
 use iced::{Alignment, Font, Length, Task};
 
 use crate::common::app_ui::AppUI;
 use crate::module::account::repository::AccountRepository;
+use crate::module::crash::model::{CrashAnalysis, CrashReportKind};
+use crate::module::crash::service::CrashService;
+use crate::module::download::entity::DownloadProgress;
+use crate::module::download::service::DownloadService;
 use crate::module::launcher::service::{LaunchArgs, LauncherService};
+use crate::module::mapping::service::MappingService;
 use crate::module::mojang::entity::MinecraftVersion;
 use crate::module::mojang::repository::MojangRepository;
+use crate::module::process::entity::{ProcessState, ProcessStatus};
+use crate::module::process::repository::ProcessRepository;
+
+/// Output lines shown when the game exits with an error
+const CRASH_OUTPUT_LINES: usize = 5;
+
+/// Output lines searched for known crash signatures
+const CRASH_SCAN_LINES: usize = 200;
 
 #[derive(Debug, Clone)]
 pub enum Message {
     PlayPressed,
-    LaunchFinished,
+    KillPressed,
+    LaunchFinished(String, Result<(), String>),
+    ProcessStateChanged(String, ProcessState),
+    CrashAnalyzed(String, CrashAnalysis),
+    ExportCrashReport,
+    CrashReportExported(Result<(), String>),
+    DownloadProgressed(DownloadProgress),
     VersionSelected(MinecraftVersion),
     VersionsLoaded(Result<Vec<MinecraftVersion>, String>),
     LocalVersionsLoaded(Vec<MinecraftVersion>),
@@ -27,12 +47,20 @@ pub struct HomeScreen {
     pub selected_version: Option<MinecraftVersion>,
     pub error: Option<String>,
     pub account_repo: Arc<AccountRepository>,
+    pub download_progress: Option<DownloadProgress>,
+    pub process_repo: Arc<ProcessRepository>,
+    /// Last known state of game processes, keyed by version id
+    pub process_states: HashMap<String, ProcessState>,
+    /// Analysis of the last crashed game, with its version id
+    pub crash: Option<(String, CrashAnalysis)>,
+    pub crash_notice: Option<String>,
 }
 
 impl HomeScreen {
     pub fn new(
         mojang_repo: Arc<MojangRepository>,
         account_repo: Arc<AccountRepository>,
+        process_repo: Arc<ProcessRepository>,
     ) -> (Self, Task<Message>) {
         let repo_clone = mojang_repo.clone();
         (
@@ -41,6 +69,11 @@ impl HomeScreen {
                 selected_version: None,
                 error: None,
                 account_repo,
+                download_progress: None,
+                process_repo,
+                process_states: HashMap::new(),
+                crash: None,
+                crash_notice: None,
             },
             Task::batch([
                 Task::perform(
@@ -104,34 +137,269 @@ impl HomeScreen {
                     );
 
                     if let Some(version) = &self.selected_version {
-                        return Task::perform(
-                            LauncherService::launch(
-                                LaunchArgs {
-                                    username: active_user.username.clone(),
-                                    ..Default::default()
-                                },
-                                version.clone(),
+                        let (progress_sender, progress_receiver) =
+                            DownloadService::progress_channel();
+                        self.download_progress = Some(DownloadProgress::default());
+                        self.crash = None;
+                        self.crash_notice = None;
+                        self.process_states
+                            .insert(version.id.clone(), ProcessState::default());
+
+                        let id = version.id.clone();
+                        return Task::batch([
+                            Task::perform(
+                                LauncherService::launch(
+                                    LaunchArgs {
+                                        username: active_user.username.clone(),
+                                        ..Default::default()
+                                    },
+                                    version.clone(),
+                                    None,
+                                    false,
+                                    progress_sender,
+                                    self.process_repo.clone(),
+                                ),
+                                move |result| Message::LaunchFinished(id.clone(), result),
+                            ),
+                            Task::run(
+                                DownloadService::progress_stream(progress_receiver),
+                                Message::DownloadProgressed,
                             ),
-                            |e| {
-                                if e.is_err() {
-                                    println!("Error: {:?}", e);
-                                    Message::LaunchFinished
-                                } else {
-                                    Message::LaunchFinished
-                                }
-                            },
-                        );
+                        ]);
                     }
                 }
             }
-            Message::LaunchFinished => {
-                println!("Game launched successfully!");
+            Message::KillPressed => {
+                if let Some(version) = &self.selected_version {
+                    self.process_repo.kill(&version.id);
+                }
+            }
+            Message::LaunchFinished(id, result) => {
+                self.download_progress = None;
+
+                match result {
+                    Ok(()) => {
+                        self.error = None;
+                        return Task::run(self.process_repo.state_stream(&id), move |state| {
+                            Message::ProcessStateChanged(id.clone(), state)
+                        });
+                    }
+                    Err(e) => {
+                        // Keep state of the game if it was already running
+                        self.process_states.remove(&id);
+                        if let Some(state) = self.process_repo.state(&id) {
+                            self.process_states.insert(id, state);
+                        }
+                        self.error = Some(e);
+                    }
+                }
+            }
+            Message::ProcessStateChanged(id, state) => {
+                let crashed = match state.status {
+                    // JVM crashes end with a signal on Unix, code is None
+                    ProcessStatus::Exited {
+                        code,
+                        duration,
+                        killed: false,
+                    } if code != Some(0) => Some((code, duration)),
+                    _ => None,
+                };
+                let output_lines = state.output_lines;
+                self.process_states.insert(id.clone(), state);
+
+                if let Some((code, duration)) = crashed {
+                    self.error = Some(match code {
+                        Some(code) => format!("{} exited with code {}", id, code),
+                        None => format!("{} was terminated", id),
+                    });
+
+                    let Ok(game_dir) = LauncherService::get_minecraft_root_dir() else {
+                        return Task::none();
+                    };
+                    let since = SystemTime::now()
+                        .checked_sub(duration)
+                        .unwrap_or(SystemTime::UNIX_EPOCH);
+                    let output = self.output_tail(&id, output_lines, CRASH_SCAN_LINES);
+
+                    return Task::perform(
+                        CrashService::analyze(game_dir, since, output),
+                        move |analysis| Message::CrashAnalyzed(id.clone(), analysis),
+                    );
+                }
+            }
+            Message::CrashAnalyzed(id, analysis) => {
+                // Show the end of the output when the crash is unknown
+                if analysis.cause.is_none()
+                    && let Some(state) = self.process_states.get(&id)
+                {
+                    let tail = self.output_tail(&id, state.output_lines, CRASH_OUTPUT_LINES);
+                    if let Some(error) = &mut self.error {
+                        error.push('\n');
+                        error.push_str(&tail.join("\n"));
+                    }
+                }
+
+                self.crash = Some((id, analysis));
+            }
+            Message::ExportCrashReport => {
+                let Some((
+                    id,
+                    CrashAnalysis {
+                        report: Some(report),
+                        ..
+                    },
+                )) = &self.crash
+                else {
+                    return Task::none();
+                };
+
+                let id = id.clone();
+                let content = report.content.clone();
+                let file_name = format!(
+                    "{}-deobf.txt",
+                    report
+                        .path
+                        .file_stem()
+                        .map(|stem| stem.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| "crash".to_owned())
+                );
+                self.crash_notice = Some("Deobfuscating...".to_owned());
+
+                return Task::perform(
+                    async move {
+                        let root_dir =
+                            LauncherService::get_minecraft_root_dir().map_err(|e| e.to_string())?;
+                        let mappings = MappingService::load(&id, &root_dir).await?;
+                        let content = mappings.deobfuscate(&content);
+
+                        let Some(handle) = rfd::AsyncFileDialog::new()
+                            .set_title("Export Deobfuscated Crash Report")
+                            .set_directory(root_dir.join("crash-reports"))
+                            .set_file_name(file_name)
+                            .save_file()
+                            .await
+                        else {
+                            return Ok(());
+                        };
+
+                        tokio::fs::write(handle.path(), content)
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                    Message::CrashReportExported,
+                );
+            }
+            Message::CrashReportExported(result) => {
+                self.crash_notice = Some(match result {
+                    Ok(()) => "Crash report exported".to_owned(),
+                    Err(e) => format!("Failed to export crash report: {}", e),
+                });
+            }
+            Message::DownloadProgressed(progress) => {
+                // Late updates can arrive after launch is finished
+                if self.download_progress.is_some() {
+                    self.download_progress = Some(progress);
+                }
             }
         }
 
         Task::none()
     }
 
+    /// Last `count` output lines of a game
+    fn output_tail(&self, id: &str, output_lines: usize, count: usize) -> Vec<String> {
+        self.process_repo
+            .output_since(id, output_lines.saturating_sub(count))
+            .1
+            .into_iter()
+            .map(|line| line.text)
+            .collect()
+    }
+
+    /// Format duration as "1h 2m 3s"
+    fn format_duration(duration: &Duration) -> String {
+        let seconds = duration.as_secs();
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+
+        if hours > 0 {
+            format!("{}h {}m {}s", hours, minutes, seconds)
+        } else if minutes > 0 {
+            format!("{}m {}s", minutes, seconds)
+        } else {
+            format!("{}s", seconds)
+        }
+    }
+
+    /// Explanation of the last crash of the selected version
+    fn view_crash(&self) -> Option<iced::Element<'_, Message>> {
+        let (id, analysis) = self.crash.as_ref()?;
+        if self.selected_version.as_ref()?.id != *id {
+            return None;
+        }
+
+        let title = match (&analysis.cause, &analysis.report) {
+            (Some(cause), _) => cause.title.as_str(),
+            (None, Some(report)) => match report.kind {
+                CrashReportKind::Game => "Game crashed",
+                CrashReportKind::Jvm => "Java virtual machine crashed",
+            },
+            (None, None) => return None,
+        };
+
+        let mut content = column![text(title).size(16).font(Font {
+            weight: iced::font::Weight::Bold,
+            ..Default::default()
+        })]
+        .spacing(4);
+
+        if let Some(cause) = &analysis.cause {
+            content = content.push(text(&cause.explanation).size(14));
+        }
+
+        if let Some(report) = &analysis.report {
+            let details = [
+                ("Description", report.description.clone()),
+                ("Error", report.exception.clone()),
+                (
+                    "Suspected mods",
+                    (!report.suspected_mods.is_empty()).then(|| report.suspected_mods.join(", ")),
+                ),
+                ("Java", report.java_version.clone()),
+                ("JVM flags", report.jvm_flags.clone()),
+                ("Report", Some(report.path.to_string_lossy().into_owned())),
+            ];
+
+            for (label, value) in details {
+                if let Some(value) = value {
+                    content = content.push(text(format!("{}: {}", label, value)).size(12));
+                }
+            }
+
+            if report.kind == CrashReportKind::Game {
+                content = content.push(
+                    row![
+                        button(text("Export deobfuscated report").size(12))
+                            .on_press(Message::ExportCrashReport)
+                            .style(button::secondary),
+                        self.crash_notice
+                            .as_deref()
+                            .map(|notice| text(notice).size(12)),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
+                );
+            }
+        }
+
+        Some(
+            container(content)
+                .padding(15)
+                .width(Length::Fill)
+                .style(container::rounded_box)
+                .into(),
+        )
+    }
+
     pub fn view(&self) -> iced::Element<'_, Message> {
         let header = column![
             text("Welcome back!").size(28).font(Font {
@@ -150,7 +418,7 @@ impl HomeScreen {
                         let is_selected = self
                             .selected_version
                             .as_ref()
-                            .map(|sv| &sv.id == &v.id)
+                            .map(|sv| sv.id == v.id)
                             .unwrap_or(false);
 
                         let content = row![
@@ -193,6 +461,68 @@ impl HomeScreen {
         .width(Length::Fill)
         .height(Length::Fill);
 
+        let download_status = self.download_progress.as_ref().map(|progress| {
+            let label = match &progress.current_file {
+                Some(file) => format!(
+                    "Downloading {}/{} files - {}",
+                    progress.finished_files, progress.total_files, file
+                ),
+                None => "Preparing game files...".to_owned(),
+            };
+
+            column![
+                text(label).size(12),
+                progress_bar(0.0..=1.0, progress.ratio()).girth(6),
+            ]
+            .spacing(6)
+        });
+
+        let process_state = self
+            .selected_version
+            .as_ref()
+            .and_then(|v| self.process_states.get(&v.id));
+
+        let process_status = process_state
+            .filter(|_| self.download_progress.is_none())
+            .map(|state| {
+                let label = match &state.status {
+                    ProcessStatus::Launching => "Launching...".to_owned(),
+                    ProcessStatus::Running { pid: Some(pid) } => format!("Running (PID {})", pid),
+                    ProcessStatus::Running { pid: None } => "Running".to_owned(),
+                    ProcessStatus::Exited {
+                        duration, killed, ..
+                    } if *killed => format!("Stopped after {}", Self::format_duration(duration)),
+                    ProcessStatus::Exited {
+                        code: Some(code),
+                        duration,
+                        ..
+                    } => format!(
+                        "Exited with code {} after {}",
+                        code,
+                        Self::format_duration(duration)
+                    ),
+                    ProcessStatus::Exited { duration, .. } => {
+                        format!("Terminated after {}", Self::format_duration(duration))
+                    }
+                };
+
+                text(label).size(12)
+            });
+
+        let launch_button = match process_state.map(|state| &state.status) {
+            Some(ProcessStatus::Running { .. }) => AppUI::button("STOP GAME")
+                .on_press(Message::KillPressed)
+                .padding([12, 40])
+                .build(),
+            Some(ProcessStatus::Launching) => {
+                AppUI::button("LAUNCHING...").padding([12, 40]).build()
+            }
+            _ => AppUI::button("LAUNCH GAME")
+                .on_press(Message::PlayPressed)
+                .padding([12, 40])
+                .build(),
+        };
+
         let footer = container(
             row![
                 column![
@@ -211,10 +541,10 @@ impl HomeScreen {
                 ]
                 .spacing(2),
                 Space::new().width(Length::Fill),
-                AppUI::button("LAUNCH GAME")
-                    .on_press(Message::PlayPressed)
-                    .padding([12, 40])
-                    .build(),
+                download_status.map(|status| container(status).width(Length::FillPortion(2))),
+                process_status,
+                Space::new().width(20),
+                launch_button,
             ]
             .align_y(Alignment::Center),
         )
@@ -237,6 +567,7 @@ impl HomeScreen {
             } else {
                 container(Space::new().height(Length::Shrink))
             },
+            self.view_crash(),
             Space::new().height(Length::FillPortion(1)),
             footer
         ]
@@ -0,0 +1,1386 @@
+// Copyright 2026 MineHighVN, AXLauncher contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use iced::widget::{
+    Space, button, column, container, grid, image, pick_list, progress_bar, row, scrollable, text,
+    text_input, toggler,
+};
+use iced::{Alignment, Background, Border, Color, Element, Font, Length, Task, Theme};
+
+use crate::module::account::repository::AccountRepository;
+use crate::module::crash::model::{CrashAnalysis, CrashReportKind};
+use crate::module::crash::service::CrashService;
+use crate::module::download::entity::DownloadProgress;
+use crate::module::download::service::DownloadService;
+use crate::module::instance::model::{Instance, InstanceOverrides, ModLoader};
+use crate::module::instance::service::InstanceService;
+use crate::module::launcher::service::{LaunchArgs, LauncherService};
+use crate::module::loader::model::LoaderVersion;
+use crate::module::loader::service::LoaderService;
+use crate::module::mods::model::{DependencyKind, IssueSeverity, ModInfo, ModIssue};
+use crate::module::mods::service::{ModService, UNLOADABLE_MODS};
+use crate::module::mojang::entity::MinecraftVersion;
+use crate::module::mojang::repository::MojangRepository;
+use crate::module::process::entity::{ProcessState, ProcessStatus};
+use crate::module::process::repository::ProcessRepository;
+
+/// Output lines shown when the game exits with an error
+const CRASH_OUTPUT_LINES: usize = 5;
+
+/// Output lines searched for known crash signatures
+const CRASH_SCAN_LINES: usize = 200;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    InstancesLoaded(Result<Vec<Instance>, String>),
+    VersionsLoaded(Result<Vec<MinecraftVersion>, String>),
+
+    CreatePressed,
+    EditPressed(String),
+    FormCancelled,
+    FormSubmitted,
+    NameChanged(String),
+    VersionSelected(String),
+    LoaderSelected(ModLoader),
+    LoaderVersionsLoaded(ModLoader, String, Result<Vec<LoaderVersion>, String>),
+    LoaderVersionSelected(LoaderVersion),
+    NotesChanged(String),
+    JavaPathChanged(String),
+    MaxMemoryChanged(String),
+    MinMemoryChanged(String),
+    JvmArgumentsChanged(String),
+    WindowWidthChanged(String),
+    WindowHeightChanged(String),
+
+    IconPressed(String),
+    IconChosen(String, Option<PathBuf>),
+    OptiFinePressed(String),
+    OptiFineChosen(String, Option<PathBuf>),
+    OptiFineInstalled(String, Result<Instance, String>),
+    DuplicatePressed(String),
+    Duplicated(Result<Instance, String>),
+    DeletePressed(String),
+    DeleteCancelled,
+    DeleteConfirmed(String),
+    Deleted(String, Result<(), String>),
+
+    ModsPressed(String),
+    ModsLoaded(String, Result<Vec<ModInfo>, String>),
+    ModToggled(usize, bool),
+    ModsClosed,
+
+    PlayPressed(String),
+    LaunchCancelled,
+    LaunchConfirmed(String),
+    KillPressed(String),
+    /// Id of the launched version on success, it differs from the game version for modded instances
+    LaunchFinished(String, Result<String, String>),
+    ProcessStateChanged(String, ProcessState),
+    CrashAnalyzed(String, CrashAnalysis),
+    DownloadProgressed(DownloadProgress),
+}
+
+/// Values of the create / edit form, numbers are kept as typed
+#[derive(Debug, Clone, Default)]
+struct InstanceForm {
+    /// Id of the edited instance, None when creating
+    editing: Option<String>,
+    name: String,
+    version: Option<String>,
+    loader: ModLoader,
+    /// None installs the newest stable loader
+    loader_version: Option<String>,
+    loader_versions: Vec<LoaderVersion>,
+    notes: String,
+    java_path: String,
+    max_memory: String,
+    min_memory: String,
+    jvm_arguments: String,
+    window_width: String,
+    window_height: String,
+}
+
+impl InstanceForm {
+    fn edit(instance: &Instance) -> Self {
+        let overrides = &instance.overrides;
+        let number = |value: Option<u32>| value.map(|v| v.to_string()).unwrap_or_default();
+
+        Self {
+            editing: Some(instance.id.clone()),
+            name: instance.name.clone(),
+            version: Some(instance.version.clone()),
+            loader: instance.loader,
+            loader_version: instance.loader_version.clone(),
+            loader_versions: Vec::new(),
+            notes: instance.notes.clone(),
+            java_path: overrides.java_path.clone().unwrap_or_default(),
+            max_memory: number(overrides.allocated_ram),
+            min_memory: number(overrides.min_ram),
+            jvm_arguments: overrides.jvm_arguments.clone().unwrap_or_default(),
+            window_width: number(overrides.window_width),
+            window_height: number(overrides.window_height),
+        }
+    }
+
+    /// Empty fields keep the global settings
+    fn overrides(&self) -> Result<InstanceOverrides, String> {
+        let text = |value: &str| Some(value.trim().to_owned()).filter(|v| !v.is_empty());
+        let number = |label: &str, value: &str| match value.trim() {
+            "" => Ok(None),
+            value => value
+                .parse::<u32>()
+                .map(Some)
+                .map_err(|_| format!("{} must be a number", label)),
+        };
+
+        Ok(InstanceOverrides {
+            java_path: text(&self.java_path),
+            allocated_ram: number("Maximum memory", &self.max_memory)?,
+            min_ram: number("Initial memory", &self.min_memory)?,
+            jvm_arguments: text(&self.jvm_arguments),
+            window_width: number("Window width", &self.window_width)?,
+            window_height: number("Window height", &self.window_height)?,
+        })
+    }
+}
+
+/// Mod jar listed with its decoded icon
+struct ModEntry {
+    info: ModInfo,
+    icon: Option<image::Handle>,
+}
+
+/// Mods of the instance being managed
+struct ModsView {
+    instance_id: String,
+    /// None while jars are being read
+    mods: Option<Vec<ModEntry>>,
+    /// Problems the game would crash on, checked again when a mod is toggled
+    issues: Vec<ModIssue>,
+}
+
+pub struct InstancesScreen {
+    account_repo: Arc<AccountRepository>,
+    process_repo: Arc<ProcessRepository>,
+
+    instances: Vec<Instance>,
+    versions: Vec<MinecraftVersion>,
+    version_ids: Vec<String>,
+
+    form: Option<InstanceForm>,
+    mods: Option<ModsView>,
+    /// Instance waiting for delete confirmation
+    pending_delete: Option<String>,
+    /// Instance whose mods failed validation, waiting to be launched anyway
+    pending_launch: Option<String>,
+    error: Option<String>,
+    notice: Option<String>,
+    /// Analysis of the last crashed game, with its instance id
+    crash: Option<(String, CrashAnalysis)>,
+
+    /// Instance being prepared with its download progress
+    download_progress: Option<(String, DownloadProgress)>,
+    /// Last known state of game processes, keyed by instance id
+    process_states: HashMap<String, ProcessState>,
+}
+
+impl InstancesScreen {
+    pub fn new(
+        mojang_repo: Arc<MojangRepository>,
+        account_repo: Arc<AccountRepository>,
+        process_repo: Arc<ProcessRepository>,
+    ) -> (Self, Task<Message>) {
+        (
+            Self {
+                account_repo,
+                process_repo,
+                instances: Vec::new(),
+                versions: Vec::new(),
+                version_ids: Vec::new(),
+                form: None,
+                mods: None,
+                pending_delete: None,
+                pending_launch: None,
+                error: None,
+                notice: None,
+                crash: None,
+                download_progress: None,
+                process_states: HashMap::new(),
+            },
+            Task::batch([
+                Task::perform(async { InstanceService::list() }, Message::InstancesLoaded),
+                Task::perform(
+                    async move {
+                        let mut versions =
+                            LauncherService::get_local_minecraft_versions().unwrap_or_default();
+                        versions.extend(mojang_repo.get_all_versions().await?);
+                        Ok(versions)
+                    },
+                    Message::VersionsLoaded,
+                ),
+            ]),
+        )
+    }
+
+    fn instance(&self, id: &str) -> Option<&Instance> {
+        self.instances.iter().find(|instance| instance.id == id)
+    }
+
+    fn instance_mut(&mut self, id: &str) -> Option<&mut Instance> {
+        self.instances.iter_mut().find(|instance| instance.id == id)
+    }
+
+    fn reload(&mut self) {
+        match InstanceService::list() {
+            Ok(instances) => self.instances = instances,
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::InstancesLoaded(Ok(instances)) => self.instances = instances,
+            Message::InstancesLoaded(Err(e)) => self.error = Some(e),
+            Message::VersionsLoaded(result) => {
+                // Local versions are listed first, remote duplicates are dropped
+                let versions = match result {
+                    Ok(versions) => versions,
+                    Err(_) => LauncherService::get_local_minecraft_versions().unwrap_or_default(),
+                };
+
+                let mut seen = HashSet::new();
+                self.versions = versions
+                    .into_iter()
+                    .filter(|v| seen.insert(v.id.clone()))
+                    .collect();
+                self.version_ids = self.versions.iter().map(|v| v.id.clone()).collect();
+            }
+
+            Message::CreatePressed => {
+                self.form = Some(InstanceForm {
+                    version: self.version_ids.first().cloned(),
+                    ..Default::default()
+                });
+                self.error = None;
+                self.notice = None;
+            }
+            Message::EditPressed(id) => {
+                self.form = self.instance(&id).map(InstanceForm::edit);
+                self.error = None;
+                self.notice = None;
+                return self.load_loader_versions();
+            }
+            Message::FormCancelled => {
+                self.form = None;
+                self.error = None;
+            }
+            Message::FormSubmitted => {
+                if let Some(form) = self.form.take() {
+                    match self.submit(&form) {
+                        Ok(()) => {
+                            self.error = None;
+                            self.reload();
+                        }
+                        Err(e) => {
+                            self.error = Some(e);
+                            self.form = Some(form);
+                        }
+                    }
+                }
+            }
+            Message::NameChanged(name) => self.edit_form(|form| form.name = name),
+            Message::VersionSelected(version) => {
+                self.edit_form(|form| {
+                    form.version = Some(version);
+                    form.loader_version = None;
+                });
+                return self.load_loader_versions();
+            }
+            Message::LoaderSelected(loader) => {
+                self.edit_form(|form| {
+                    form.loader = loader;
+                    form.loader_version = None;
+                });
+                return self.load_loader_versions();
+            }
+            Message::LoaderVersionsLoaded(loader, game_version, result) => {
+                let Some(form) = &mut self.form else {
+                    return Task::none();
+                };
+
+                // Form changed while loading
+                if form.loader != loader || form.version.as_ref() != Some(&game_version) {
+                    return Task::none();
+                }
+
+                match result {
+                    Ok(versions) => form.loader_versions = versions,
+                    Err(e) => {
+                        self.error = Some(format!("Failed to load {} versions: {}", loader, e))
+                    }
+                }
+            }
+            Message::LoaderVersionSelected(version) => {
+                self.edit_form(|form| form.loader_version = Some(version.version))
+            }
+            Message::NotesChanged(notes) => self.edit_form(|form| form.notes = notes),
+            Message::JavaPathChanged(path) => self.edit_form(|form| form.java_path = path),
+            Message::MaxMemoryChanged(value) => self.edit_form(|form| form.max_memory = value),
+            Message::MinMemoryChanged(value) => self.edit_form(|form| form.min_memory = value),
+            Message::JvmArgumentsChanged(value) => {
+                self.edit_form(|form| form.jvm_arguments = value)
+            }
+            Message::WindowWidthChanged(value) => self.edit_form(|form| form.window_width = value),
+            Message::WindowHeightChanged(value) => {
+                self.edit_form(|form| form.window_height = value)
+            }
+
+            Message::IconPressed(id) => {
+                return Task::perform(
+                    async {
+                        rfd::AsyncFileDialog::new()
+                            .set_title("Choose Instance Icon")
+                            .add_filter("Image", &["png", "jpg", "jpeg", "gif", "webp"])
+                            .pick_file()
+                            .await
+                            .map(|handle| handle.path().to_path_buf())
+                    },
+                    move |path| Message::IconChosen(id.clone(), path),
+                );
+            }
+            Message::IconChosen(id, Some(path)) => {
+                if let Some(instance) = self.instance_mut(&id)
+                    && let Err(e) = InstanceService::set_icon(instance, &path)
+                {
+                    self.error = Some(e);
+                }
+            }
+            Message::IconChosen(_, None) => {}
+            Message::OptiFinePressed(id) => {
+                return Task::perform(
+                    async {
+                        rfd::AsyncFileDialog::new()
+                            .set_title("Choose OptiFine Jar")
+                            .add_filter("OptiFine", &["jar"])
+                            .pick_file()
+                            .await
+                            .map(|handle| handle.path().to_path_buf())
+                    },
+                    move |path| Message::OptiFineChosen(id.clone(), path),
+                );
+            }
+            Message::OptiFineChosen(id, Some(path)) => return self.install_optifine(&id, path),
+            Message::OptiFineChosen(_, None) => {}
+            Message::OptiFineInstalled(id, result) => {
+                self.download_progress = None;
+
+                match result {
+                    Ok(instance) => {
+                        self.error = None;
+                        self.notice = Some(match instance.loader {
+                            ModLoader::Vanilla => {
+                                format!("{} now uses {}", instance.name, instance.version)
+                            }
+                            ModLoader::Fabric | ModLoader::Quilt => {
+                                "OptiFine added to mods, it also needs OptiFabric".to_owned()
+                            }
+                            _ => "OptiFine added to mods".to_owned(),
+                        });
+
+                        if let Some(form) = &mut self.form
+                            && form.editing.as_ref() == Some(&id)
+                        {
+                            form.version = Some(instance.version.clone());
+                        }
+                        if !self.version_ids.contains(&instance.version) {
+                            self.versions.insert(
+                                0,
+                                MinecraftVersion {
+                                    id: instance.version.clone(),
+                                    version_type: "modded".to_owned(),
+                                    url: None,
+                                    available: true,
+                                },
+                            );
+                            self.version_ids.insert(0, instance.version.clone());
+                        }
+                        if let Some(current) = self.instance_mut(&id) {
+                            current.version = instance.version;
+                        }
+                    }
+                    Err(e) => self.error = Some(format!("Failed to install OptiFine: {}", e)),
+                }
+            }
+            Message::DuplicatePressed(id) => {
+                if let Some(instance) = self.instance(&id) {
+                    return Task::perform(
+                        InstanceService::duplicate(instance.clone()),
+                        Message::Duplicated,
+                    );
+                }
+            }
+            Message::Duplicated(result) => match result {
+                Ok(_) => self.reload(),
+                Err(e) => self.error = Some(e),
+            },
+            Message::DeletePressed(id) => self.pending_delete = Some(id),
+            Message::DeleteCancelled => self.pending_delete = None,
+            Message::DeleteConfirmed(id) => {
+                self.pending_delete = None;
+
+                let running = self
+                    .process_repo
+                    .state(&id)
+                    .is_some_and(|state| state.status.is_active());
+                if running {
+                    self.error = Some("Stop the game before deleting the instance".to_owned());
+                    return Task::none();
+                }
+
+                if let Some(instance) = self.instance(&id) {
+                    return Task::perform(InstanceService::delete(instance.clone()), move |r| {
+                        Message::Deleted(id.clone(), r)
+                    });
+                }
+            }
+            Message::Deleted(id, result) => {
+                match result {
+                    Ok(()) => self.error = None,
+                    Err(e) => self.error = Some(e),
+                }
+                self.process_states.remove(&id);
+                self.reload();
+            }
+
+            Message::ModsPressed(id) => {
+                let Some(instance) = self.instance(&id).cloned() else {
+                    return Task::none();
+                };
+
+                self.mods = Some(ModsView {
+                    instance_id: id.clone(),
+                    mods: None,
+                    issues: Vec::new(),
+                });
+                self.error = None;
+                self.notice = None;
+
+                return Task::perform(ModService::list(instance), move |result| {
+                    Message::ModsLoaded(id.clone(), result)
+                });
+            }
+            Message::ModsLoaded(id, result) => {
+                let Some(view) = self.mods.as_mut().filter(|view| view.instance_id == id) else {
+                    return Task::none();
+                };
+
+                match result {
+                    Ok(mods) => {
+                        view.mods = Some(
+                            mods.into_iter()
+                                .map(|info| ModEntry {
+                                    icon: info.icon.clone().map(image::Handle::from_bytes),
+                                    info,
+                                })
+                                .collect(),
+                        );
+                    }
+                    Err(e) => {
+                        view.mods = Some(Vec::new());
+                        self.error = Some(e);
+                    }
+                }
+                self.validate_mods();
+            }
+            Message::ModToggled(index, enabled) => {
+                if let Some(entry) = self
+                    .mods
+                    .as_mut()
+                    .and_then(|view| view.mods.as_mut())
+                    .and_then(|mods| mods.get_mut(index))
+                    && let Err(e) = ModService::set_enabled(&mut entry.info, enabled)
+                {
+                    self.error = Some(e);
+                }
+                self.validate_mods();
+            }
+            Message::ModsClosed => {
+                self.mods = None;
+                self.error = None;
+            }
+
+            Message::PlayPressed(id) => {
+                self.pending_launch = None;
+                return self.launch(&id, false);
+            }
+            Message::LaunchCancelled => {
+                self.pending_launch = None;
+                self.error = None;
+            }
+            Message::LaunchConfirmed(id) => {
+                self.pending_launch = None;
+                return self.launch(&id, true);
+            }
+            Message::KillPressed(id) => self.process_repo.kill(&id),
+            Message::LaunchFinished(id, result) => {
+                self.download_progress = None;
+                // Loader version may have been pinned by the launch
+                self.reload();
+
+                match result {
+                    Ok(_) => {
+                        self.error = None;
+                        return Task::run(self.process_repo.state_stream(&id), move |state| {
+                            Message::ProcessStateChanged(id.clone(), state)
+                        });
+                    }
+                    Err(e) => {
+                        // Keep state of the game if it was already running
+                        self.process_states.remove(&id);
+                        if let Some(state) = self.process_repo.state(&id) {
+                            self.process_states.insert(id.clone(), state);
+                        }
+                        // Mods may still load, the user can launch anyway
+                        if e.starts_with(UNLOADABLE_MODS) {
+                            self.pending_launch = Some(id);
+                        }
+                        self.error = Some(e);
+                    }
+                }
+            }
+            Message::ProcessStateChanged(id, state) => {
+                let crashed = match state.status {
+                    // JVM crashes end with a signal on Unix, code is None
+                    ProcessStatus::Exited {
+                        code,
+                        duration,
+                        killed: false,
+                    } if code != Some(0) => Some((code, duration)),
+                    _ => None,
+                };
+                let output_lines = state.output_lines;
+                self.process_states.insert(id.clone(), state);
+
+                if let Some((code, duration)) = crashed {
+                    let Some(instance) = self.instance(&id) else {
+                        return Task::none();
+                    };
+                    // Crash reports are written into the game directory of the instance
+                    let game_dir = instance.path.clone();
+                    self.error = Some(match code {
+                        Some(code) => format!("{} exited with code {}", instance.name, code),
+                        None => format!("{} was terminated", instance.name),
+                    });
+
+                    let since = SystemTime::now()
+                        .checked_sub(duration)
+                        .unwrap_or(SystemTime::UNIX_EPOCH);
+                    let output = self.output_tail(&id, output_lines, CRASH_SCAN_LINES);
+
+                    return Task::perform(
+                        CrashService::analyze(game_dir, since, output),
+                        move |analysis| Message::CrashAnalyzed(id.clone(), analysis),
+                    );
+                }
+            }
+            Message::CrashAnalyzed(id, analysis) => {
+                // Show the end of the output when the crash is unknown
+                if analysis.cause.is_none()
+                    && let Some(state) = self.process_states.get(&id)
+                {
+                    let tail = self.output_tail(&id, state.output_lines, CRASH_OUTPUT_LINES);
+                    if let Some(error) = &mut self.error {
+                        error.push('\n');
+                        error.push_str(&tail.join("\n"));
+                    }
+                }
+
+                self.crash = Some((id, analysis));
+            }
+            Message::DownloadProgressed(progress) => {
+                // Late updates can arrive after launch is finished
+                if let Some((_, current)) = &mut self.download_progress {
+                    *current = progress;
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    /// Last `count` output lines of a game
+    fn output_tail(&self, id: &str, output_lines: usize, count: usize) -> Vec<String> {
+        self.process_repo
+            .output_since(id, output_lines.saturating_sub(count))
+            .1
+            .into_iter()
+            .map(|line| line.text)
+            .collect()
+    }
+
+    fn validate_mods(&mut self) {
+        let Some(view) = &self.mods else {
+            return;
+        };
+        let Some(instance) = self.instance(&view.instance_id) else {
+            return;
+        };
+
+        let mods: Vec<ModInfo> = view
+            .mods
+            .iter()
+            .flatten()
+            .map(|entry| entry.info.clone())
+            .collect();
+        let issues = ModService::validate(&mods, instance);
+
+        if let Some(view) = &mut self.mods {
+            view.issues = issues;
+        }
+    }
+
+    /// Fetch loader versions compatible with the version selected in the form
+    fn load_loader_versions(&mut self) -> Task<Message> {
+        let Some(form) = &mut self.form else {
+            return Task::none();
+        };
+
+        form.loader_versions.clear();
+
+        let loader = form.loader;
+        let Some(game_version) = form.version.clone() else {
+            return Task::none();
+        };
+        if loader == ModLoader::Vanilla {
+            return Task::none();
+        }
+
+        Task::perform(
+            {
+                let game_version = game_version.clone();
+                async move { LoaderService::get_versions(loader, &game_version).await }
+            },
+            move |result| Message::LoaderVersionsLoaded(loader, game_version.clone(), result),
+        )
+    }
+
+    fn edit_form(&mut self, edit: impl FnOnce(&mut InstanceForm)) {
+        if let Some(form) = &mut self.form {
+            edit(form);
+        }
+    }
+
+    /// Create a new instance or save the edited one
+    fn submit(&mut self, form: &InstanceForm) -> Result<(), String> {
+        let version = form
+            .version
+            .clone()
+            .ok_or_else(|| "Select a game version".to_owned())?;
+        let overrides = form.overrides()?;
+
+        match &form.editing {
+            Some(id) => {
+                let instance = self
+                    .instance_mut(id)
+                    .ok_or_else(|| format!("Instance {} not found", id))?;
+
+                instance.version = version;
+                instance.loader = form.loader;
+                instance.loader_version = form.loader_version.clone();
+                instance.notes = form.notes.clone();
+                instance.overrides = overrides;
+                InstanceService::rename(instance, &form.name)
+            }
+            None => {
+                let mut instance = InstanceService::create(&form.name, &version, form.loader)?;
+                instance.loader_version = form.loader_version.clone();
+                instance.notes = form.notes.clone();
+                instance.overrides = overrides;
+                InstanceService::save(&instance)
+            }
+        }
+    }
+
+    fn install_optifine(&mut self, id: &str, jar: PathBuf) -> Task<Message> {
+        let Some(instance) = self.instance(id).cloned() else {
+            return Task::none();
+        };
+
+        let (progress_sender, progress_receiver) = DownloadService::progress_channel();
+        self.download_progress = Some((instance.id.clone(), DownloadProgress::default()));
+        self.error = None;
+        self.notice = None;
+
+        let id = instance.id.clone();
+        Task::batch([
+            Task::perform(
+                async move { LoaderService::install_optifine(instance, jar, &progress_sender).await },
+                move |result| Message::OptiFineInstalled(id.clone(), result),
+            ),
+            Task::run(
+                DownloadService::progress_stream(progress_receiver),
+                Message::DownloadProgressed,
+            ),
+        ])
+    }
+
+    /// Mods are checked first unless `skip_mod_check` is set by "Launch anyway"
+    fn launch(&mut self, id: &str, skip_mod_check: bool) -> Task<Message> {
+        let Some(mut instance) = self.instance(id).cloned() else {
+            return Task::none();
+        };
+        let Some(account) = self.account_repo.get_active() else {
+            self.error = Some("Add an account before playing".to_owned());
+            return Task::none();
+        };
+
+        // Installed versions may not be listed when offline
+        let version = self
+            .versions
+            .iter()
+            .find(|v| v.id == instance.version)
+            .cloned()
+            .unwrap_or_else(|| MinecraftVersion {
+                id: instance.version.clone(),
+                version_type: "release".to_owned(),
+                url: None,
+                available: true,
+            });
+
+        let (progress_sender, progress_receiver) = DownloadService::progress_channel();
+        self.download_progress = Some((instance.id.clone(), DownloadProgress::default()));
+        self.process_states
+            .insert(instance.id.clone(), ProcessState::default());
+        self.error = None;
+        self.crash = None;
+
+        let id = instance.id.clone();
+        let process_repo = self.process_repo.clone();
+        Task::batch([
+            Task::perform(
+                async move {
+                    // Modded instances launch the version created by the loader
+                    let version = match instance.loader {
+                        ModLoader::Vanilla => version,
+                        _ => MinecraftVersion {
+                            id: LoaderService::install(&mut instance, &progress_sender).await?,
+                            version_type: "modded".to_owned(),
+                            url: None,
+                            available: true,
+                        },
+                    };
+
+                    let version_id = version.id.clone();
+                    LauncherService::launch(
+                        LaunchArgs {
+                            username: account.username,
+                            ..Default::default()
+                        },
+                        version,
+                        Some(instance),
+                        skip_mod_check,
+                        progress_sender,
+                        process_repo,
+                    )
+                    .await
+                    .map(|()| version_id)
+                },
+                move |result| Message::LaunchFinished(id.clone(), result),
+            ),
+            Task::run(
+                DownloadService::progress_stream(progress_receiver),
+                Message::DownloadProgressed,
+            ),
+        ])
+    }
+
+    /// Explanation of the last crash of an instance
+    fn view_crash(&self) -> Option<Element<'_, Message>> {
+        let (id, analysis) = self.crash.as_ref()?;
+        let instance = self.instance(id)?;
+
+        let title = match (&analysis.cause, &analysis.report) {
+            (Some(cause), _) => cause.title.as_str(),
+            (None, Some(report)) => match report.kind {
+                CrashReportKind::Game => "Game crashed",
+                CrashReportKind::Jvm => "Java virtual machine crashed",
+            },
+            (None, None) => return None,
+        };
+
+        let mut content =
+            column![
+                text(format!("{}: {}", instance.name, title))
+                    .size(16)
+                    .font(Font {
+                        weight: iced::font::Weight::Bold,
+                        ..Default::default()
+                    })
+            ]
+            .spacing(4);
+
+        if let Some(cause) = &analysis.cause {
+            content = content.push(text(&cause.explanation).size(14));
+        }
+
+        if let Some(report) = &analysis.report {
+            let details = [
+                ("Description", report.description.clone()),
+                ("Error", report.exception.clone()),
+                (
+                    "Suspected mods",
+                    (!report.suspected_mods.is_empty()).then(|| report.suspected_mods.join(", ")),
+                ),
+                ("Java", report.java_version.clone()),
+                ("Report", Some(report.path.to_string_lossy().into_owned())),
+            ];
+
+            for (label, value) in details {
+                if let Some(value) = value {
+                    content = content.push(text(format!("{}: {}", label, value)).size(12));
+                }
+            }
+        }
+
+        Some(
+            container(content)
+                .padding(15)
+                .width(Length::Fill)
+                .style(container::rounded_box)
+                .into(),
+        )
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let header = row![
+            column![
+                text("Instances").size(28).font(Font {
+                    weight: iced::font::Weight::Bold,
+                    ..Default::default()
+                }),
+                text("Each instance keeps its own mods, worlds and settings").size(14),
+            ]
+            .spacing(5),
+            Space::new().width(Length::Fill),
+            (self.form.is_none() && self.mods.is_none()).then(|| {
+                button(text("New Instance").size(14))
+                    .on_press(Message::CreatePressed)
+                    .padding([10, 20])
+                    .style(button::primary)
+            }),
+        ]
+        .align_y(Alignment::Center);
+
+        let content = match (&self.form, &self.mods) {
+            (_, Some(mods)) => self.view_mods(mods),
+            (Some(form), _) => self.view_form(form),
+            (None, None) if self.instances.is_empty() => container(
+                text("No instances yet, create one to get started").style(text::secondary),
+            )
+            .center(Length::Fill)
+            .into(),
+            (None, None) => scrollable(
+                grid(
+                    self.instances
+                        .iter()
+                        .map(|instance| self.view_card(instance)),
+                )
+                .fluid(280)
+                .spacing(15)
+                .height(grid::Sizing::EvenlyDistribute(Length::Shrink)),
+            )
+            .height(Length::Fill)
+            .into(),
+        };
+
+        column![
+            header,
+            self.error
+                .as_deref()
+                .map(|error| text(error).size(14).style(text::danger)),
+            self.notice
+                .as_deref()
+                .map(|notice| text(notice).size(14).style(text::success)),
+            self.view_crash(),
+            content,
+        ]
+        .spacing(20)
+        .padding(30)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    fn view_card<'a>(&'a self, instance: &'a Instance) -> Element<'a, Message> {
+        let id = &instance.id;
+        let status = self.process_states.get(id).map(|state| &state.status);
+
+        let icon: Element<'_, Message> = match instance.icon_path() {
+            Some(path) => image(path).width(48).height(48).into(),
+            None => {
+                container(text(instance.name.chars().next().unwrap_or('?').to_string()).size(22))
+                    .style(|t: &Theme| container::Style {
+                        background: Some(t.extended_palette().primary.base.color.into()),
+                        border: Border {
+                            radius: 10.0.into(),
+                            ..Default::default()
+                        },
+                        text_color: Some(Color::WHITE),
+                        ..Default::default()
+                    })
+                    .width(48)
+                    .height(48)
+                    .align_x(Alignment::Center)
+                    .align_y(Alignment::Center)
+                    .into()
+            }
+        };
+
+        let summary = match instance.loader {
+            ModLoader::Vanilla => instance.version.clone(),
+            loader => format!("{} - {}", instance.version, loader),
+        };
+
+        let state_label = match status {
+            Some(ProcessStatus::Launching) => Some("Launching...".to_owned()),
+            Some(ProcessStatus::Running { .. }) => Some("Running".to_owned()),
+            Some(ProcessStatus::Exited {
+                code: Some(code), ..
+            }) if *code != 0 => Some(format!("Exited with code {}", code)),
+            _ => None,
+        };
+
+        let progress = self
+            .download_progress
+            .as_ref()
+            .filter(|(launching, _)| launching == id)
+            .map(|(_, progress)| progress_bar(0.0..=1.0, progress.ratio()).girth(4));
+
+        let play_button = match status {
+            Some(ProcessStatus::Running { .. }) => button(text("Stop").size(13))
+                .on_press(Message::KillPressed(id.clone()))
+                .style(button::danger),
+            Some(ProcessStatus::Launching) => button(text("Launching").size(13)),
+            _ => button(text("Play").size(13))
+                .on_press_maybe(
+                    self.download_progress
+                        .is_none()
+                        .then(|| Message::PlayPressed(id.clone())),
+                )
+                .style(button::primary),
+        };
+
+        let actions: Element<'_, Message> = if self.pending_delete.as_ref() == Some(id) {
+            row![
+                text("Delete everything?").size(12),
+                Space::new().width(Length::Fill),
+                button(text("Cancel").size(13))
+                    .on_press(Message::DeleteCancelled)
+                    .style(button::text),
+                button(text("Delete").size(13))
+                    .on_press(Message::DeleteConfirmed(id.clone()))
+                    .style(button::danger),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center)
+            .into()
+        } else if self.pending_launch.as_ref() == Some(id) {
+            row![
+                text("Mods may crash the game").size(12),
+                Space::new().width(Length::Fill),
+                button(text("Cancel").size(13))
+                    .on_press(Message::LaunchCancelled)
+                    .style(button::text),
+                button(text("Launch anyway").size(13))
+                    .on_press(Message::LaunchConfirmed(id.clone()))
+                    .style(button::danger),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center)
+            .into()
+        } else {
+            row![
+                play_button,
+                Space::new().width(Length::Fill),
+                (instance.loader != ModLoader::Vanilla).then(|| {
+                    button(text("Mods").size(13))
+                        .on_press(Message::ModsPressed(id.clone()))
+                        .style(button::secondary)
+                }),
+                button(text("Edit").size(13))
+                    .on_press(Message::EditPressed(id.clone()))
+                    .style(button::secondary),
+                button(text("Icon").size(13))
+                    .on_press(Message::IconPressed(id.clone()))
+                    .style(button::secondary),
+                button(text("Copy").size(13))
+                    .on_press(Message::DuplicatePressed(id.clone()))
+                    .style(button::secondary),
+                button(text("Delete").size(13))
+                    .on_press(Message::DeletePressed(id.clone()))
+                    .style(button::text),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center)
+            .into()
+        };
+
+        container(
+            column![
+                row![
+                    icon,
+                    column![
+                        text(&instance.name).size(16).font(Font {
+                            weight: iced::font::Weight::Semibold,
+                            ..Default::default()
+                        }),
+                        text(summary).size(12).style(text::secondary),
+                        state_label.map(|label| text(label).size(12)),
+                    ]
+                    .spacing(2),
+                ]
+                .spacing(12)
+                .align_y(Alignment::Center),
+                (!instance.notes.is_empty())
+                    .then(|| text(&instance.notes).size(12).style(text::secondary)),
+                progress,
+                actions,
+            ]
+            .spacing(12),
+        )
+        .padding(15)
+        .width(Length::Fill)
+        .style(|theme: &Theme| {
+            let palette = theme.extended_palette();
+            container::Style {
+                background: Some(Background::Color(palette.background.weak.color)),
+                border: Border {
+                    width: 1.0,
+                    color: palette.background.strong.color,
+                    radius: 12.0.into(),
+                },
+                ..Default::default()
+            }
+        })
+        .into()
+    }
+
+    fn view_mods<'a>(&'a self, view: &'a ModsView) -> Element<'a, Message> {
+        let name = self
+            .instance(&view.instance_id)
+            .map(|instance| instance.name.as_str())
+            .unwrap_or_default();
+
+        let list: Element<'_, Message> = match &view.mods {
+            None => container(text("Reading mods...").style(text::secondary))
+                .center(Length::Fill)
+                .into(),
+            Some(mods) if mods.is_empty() => container(
+                text("No mods yet, put mod jars in the mods folder").style(text::secondary),
+            )
+            .center(Length::Fill)
+            .into(),
+            Some(mods) => scrollable(
+                column(
+                    mods.iter()
+                        .enumerate()
+                        .map(|(index, entry)| Self::view_mod(index, entry)),
+                )
+                .spacing(10),
+            )
+            .height(Length::Fill)
+            .into(),
+        };
+
+        let count = view.mods.as_ref().map(|mods| {
+            let enabled = mods.iter().filter(|entry| entry.info.enabled).count();
+            text(format!("{} of {} enabled", enabled, mods.len()))
+                .size(13)
+                .style(text::secondary)
+        });
+
+        column![
+            row![
+                button(text("Back").size(14))
+                    .on_press(Message::ModsClosed)
+                    .style(button::text),
+                text(format!("{} - Mods", name)).size(18),
+                Space::new().width(Length::Fill),
+                count,
+                button(text("Refresh").size(13))
+                    .on_press(Message::ModsPressed(view.instance_id.clone()))
+                    .style(button::secondary),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            (!view.issues.is_empty()).then(|| {
+                column(view.issues.iter().map(|issue| {
+                    let style = match issue.severity {
+                        IssueSeverity::Error => text::danger,
+                        IssueSeverity::Warning => text::secondary,
+                    };
+                    text(&issue.message).size(13).style(style).into()
+                }))
+                .spacing(4)
+            }),
+            list,
+        ]
+        .spacing(15)
+        .into()
+    }
+
+    fn view_mod(index: usize, entry: &ModEntry) -> Element<'_, Message> {
+        let info = &entry.info;
+
+        let icon: Element<'_, Message> = match &entry.icon {
+            Some(handle) => image(handle.clone()).width(40).height(40).into(),
+            None => container(text(info.name.chars().next().unwrap_or('?').to_string()).size(18))
+                .style(|t: &Theme| container::Style {
+                    background: Some(t.extended_palette().background.strong.color.into()),
+                    border: Border {
+                        radius: 8.0.into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .width(40)
+                .height(40)
+                .align_x(Alignment::Center)
+                .align_y(Alignment::Center)
+                .into(),
+        };
+
+        let mut details = vec![info.file_name()];
+        if let Some(format) = info.format {
+            details.insert(0, format.to_string());
+        }
+        if !info.authors.is_empty() {
+            details.push(format!("by {}", info.authors.join(", ")));
+        }
+
+        let dependencies: Vec<&str> = info
+            .dependencies
+            .iter()
+            .filter(|dependency| dependency.kind == DependencyKind::Required)
+            .map(|dependency| dependency.id.as_str())
+            .collect();
+
+        container(
+            row![
+                icon,
+                column![
+                    row![
+                        text(&info.name).size(15).font(Font {
+                            weight: iced::font::Weight::Semibold,
+                            ..Default::default()
+                        }),
+                        text(&info.version).size(12).style(text::secondary),
+                    ]
+                    .spacing(8)
+                    .align_y(Alignment::Center),
+                    text(details.join(" - ")).size(12).style(text::secondary),
+                    (!info.description.is_empty()).then(|| text(&info.description).size(12)),
+                    (!dependencies.is_empty()).then(|| {
+                        text(format!("Requires {}", dependencies.join(", ")))
+                            .size(12)
+                            .style(text::secondary)
+                    }),
+                ]
+                .spacing(3)
+                .width(Length::Fill),
+                toggler(info.enabled).on_toggle(move |enabled| Message::ModToggled(index, enabled)),
+            ]
+            .spacing(12)
+            .align_y(Alignment::Center),
+        )
+        .padding(12)
+        .width(Length::Fill)
+        .style(|theme: &Theme| {
+            let palette = theme.extended_palette();
+            container::Style {
+                background: Some(Background::Color(palette.background.weak.color)),
+                border: Border {
+                    width: 1.0,
+                    color: palette.background.strong.color,
+                    radius: 10.0.into(),
+                },
+                ..Default::default()
+            }
+        })
+        .into()
+    }
+
+    fn view_form<'a>(&'a self, form: &'a InstanceForm) -> Element<'a, Message> {
+        let field = |label: &'a str, input: Element<'a, Message>| -> Element<'a, Message> {
+            column![text(label).size(13), input].spacing(6).into()
+        };
+
+        let input = |placeholder: &'a str, value: &'a str, on_input: fn(String) -> Message| {
+            text_input(placeholder, value)
+                .on_input(on_input)
+                .padding(10)
+        };
+
+        let title = match form.editing {
+            Some(_) => "Edit Instance",
+            None => "New Instance",
+        };
+
+        let submit_label = match form.editing {
+            Some(_) => "Save",
+            None => "Create",
+        };
+
+        scrollable(
+            column![
+                text(title).size(18),
+                field(
+                    "Name",
+                    input("My instance", &form.name, Message::NameChanged).into()
+                ),
+                row![
+                    field(
+                        "Version",
+                        pick_list(
+                            self.version_ids.as_slice(),
+                            form.version.as_ref(),
+                            Message::VersionSelected
+                        )
+                        .placeholder("Select a version")
+                        .width(Length::Fill)
+                        .into()
+                    ),
+                    field(
+                        "Mod loader",
+                        pick_list(ModLoader::ALL, Some(form.loader), Message::LoaderSelected)
+                            .width(Length::Fill)
+                            .into()
+                    ),
+                ]
+                .spacing(15),
+                (form.loader != ModLoader::Vanilla).then(|| field(
+                    "Loader version",
+                    pick_list(
+                        form.loader_versions.as_slice(),
+                        form.loader_versions
+                            .iter()
+                            .find(|v| form.loader_version.as_ref() == Some(&v.version)),
+                        Message::LoaderVersionSelected
+                    )
+                    .placeholder(match &form.loader_version {
+                        Some(version) => version.as_str(),
+                        None => "Latest stable",
+                    })
+                    .width(Length::Fill)
+                    .into()
+                )),
+                form.editing.as_ref().map(|id| {
+                    field(
+                        "OptiFine",
+                        column![
+                            row![
+                                text(match form.loader {
+                                    ModLoader::Vanilla =>
+                                        "Creates a version with OptiFine for this instance",
+                                    _ => "Adds the OptiFine jar to the mods of this instance",
+                                })
+                                .size(12)
+                                .style(text::secondary),
+                                Space::new().width(Length::Fill),
+                                button(text("Install from jar...").size(13))
+                                    .on_press_maybe(
+                                        self.download_progress
+                                            .is_none()
+                                            .then(|| Message::OptiFinePressed(id.clone())),
+                                    )
+                                    .style(button::secondary),
+                            ]
+                            .align_y(Alignment::Center),
+                            self.download_progress
+                                .as_ref()
+                                .filter(|(installing, _)| installing == id)
+                                .map(|(_, progress)| progress_bar(0.0..=1.0, progress.ratio())
+                                    .girth(4)),
+                        ]
+                        .spacing(6)
+                        .into(),
+                    )
+                }),
+                field(
+                    "Notes",
+                    input("", &form.notes, Message::NotesChanged).into()
+                ),
+                text("OVERRIDES").size(12).font(Font {
+                    weight: iced::font::Weight::Bold,
+                    ..Default::default()
+                }),
+                text("Empty fields use the global settings")
+                    .size(12)
+                    .style(text::secondary),
+                field(
+                    "Java executable",
+                    input("Global setting", &form.java_path, Message::JavaPathChanged).into()
+                ),
+                row![
+                    field(
+                        "Maximum memory (MB)",
+                        input(
+                            "Global setting",
+                            &form.max_memory,
+                            Message::MaxMemoryChanged
+                        )
+                        .into()
+                    ),
+                    field(
+                        "Initial memory (MB)",
+                        input(
+                            "Global setting",
+                            &form.min_memory,
+                            Message::MinMemoryChanged
+                        )
+                        .into()
+                    ),
+                ]
+                .spacing(15),
+                field(
+                    "JVM arguments",
+                    input(
+                        "Global setting",
+                        &form.jvm_arguments,
+                        Message::JvmArgumentsChanged
+                    )
+                    .into()
+                ),
+                row![
+                    field(
+                        "Window width",
+                        input("Default", &form.window_width, Message::WindowWidthChanged).into()
+                    ),
+                    field(
+                        "Window height",
+                        input("Default", &form.window_height, Message::WindowHeightChanged).into()
+                    ),
+                ]
+                .spacing(15),
+                row![
+                    button("Cancel")
+                        .on_press(Message::FormCancelled)
+                        .style(button::text),
+                    Space::new().width(Length::Fill),
+                    button(submit_label)
+                        .on_press(Message::FormSubmitted)
+                        .style(button::primary),
+                ],
+            ]
+            .spacing(15)
+            .max_width(600),
+        )
+        .height(Length::Fill)
+        .into()
+    }
+}
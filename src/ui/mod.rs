@@ -2,5 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod accounts;
+pub mod console;
 pub mod home;
+pub mod instances;
 pub mod settings;
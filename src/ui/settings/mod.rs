@@ -1,23 +1,39 @@
 // Copyright 2026 MineHighVN, AXLauncher contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use std::path::PathBuf;
+
 use iced::widget::{
-    Space, button, column, container, pick_list, row, scrollable, slider, text, text_input,
+    Space, button, column, container, pick_list, row, scrollable, slider, text, text_input, toggler,
 };
 use iced::{
     Alignment, Background, Border, Color, Element, Font, Length, Shadow, Task, Theme, Vector,
 };
 
-use crate::module::config::model::AppConfig;
+use crate::module::config::model::{AppConfig, DEFAULT_FABRIC_META_URL, DEFAULT_QUILT_META_URL};
 use crate::module::config::repository::ConfigRepository;
+use crate::module::java::model::JavaInstallation;
+use crate::module::java::service::JavaService;
 
 #[derive(Debug, Clone)]
 pub enum Message {
     RamChanged(u32),
+    MinRamChanged(u32),
+    JvmArgumentsChanged(String),
+    GameArgumentsChanged(String),
+    EnvironmentVariablesChanged(String),
+    WrapperCommandChanged(String),
+    FabricMetaUrlChanged(String),
+    QuiltMetaUrlChanged(String),
     JavaPathChanged(String),
     MinecraftRootDirChanged(String),
     LanguageChanged(String),
     ThemeChanged(Theme),
+    VerifyFileHashesToggled(bool),
+    JavaInstallationsLoaded(Vec<JavaInstallation>),
+    JavaInstallationSelected(JavaInstallation),
+    TestJava,
+    JavaTested(Result<JavaInstallation, String>),
     BrowseMinecraftDir,
     CheckForUpdates,
     OpenGithub,
@@ -26,41 +42,98 @@ pub enum Message {
 
 pub struct SettingsScreen {
     pub allocated_ram: u32,
+    pub min_ram: u32,
     pub java_path: String,
     pub selected_language: String,
     pub current_theme: Theme,
     pub minecraft_root_dir: String,
+    pub verify_file_hashes: bool,
+    pub jvm_arguments: String,
+    pub game_arguments: String,
+    pub environment_variables: String,
+    pub wrapper_command: String,
+    pub fabric_meta_url: String,
+    pub quilt_meta_url: String,
+    pub java_installations: Vec<JavaInstallation>,
+    pub java_test_result: Option<Result<JavaInstallation, String>>,
 }
 
 impl SettingsScreen {
-    pub fn new(theme: Theme) -> Self {
-        Self {
-            allocated_ram: 4096,
-            java_path: "/usr/bin/java".to_owned(),
-            minecraft_root_dir: "../.minecraft".to_owned(),
-            selected_language: "English".to_owned(),
-            current_theme: theme,
-        }
+    pub fn new(theme: Theme) -> (Self, Task<Message>) {
+        let config = ConfigRepository::load();
+
+        (
+            Self {
+                allocated_ram: config.allocated_ram,
+                min_ram: config.min_ram,
+                java_path: config.java_path,
+                minecraft_root_dir: config.minecraft_root_dir,
+                selected_language: config.language,
+                current_theme: theme,
+                verify_file_hashes: config.verify_file_hashes,
+                jvm_arguments: config.jvm_arguments,
+                game_arguments: config.game_arguments,
+                environment_variables: config.environment_variables,
+                wrapper_command: config.wrapper_command,
+                fabric_meta_url: config.fabric_meta_url,
+                quilt_meta_url: config.quilt_meta_url,
+                java_installations: Vec::new(),
+                java_test_result: None,
+            },
+            Task::perform(JavaService::discover(), Message::JavaInstallationsLoaded),
+        )
     }
 
     pub fn update(&mut self, message: Message) -> Task<Message> {
         match message {
-            Message::RamChanged(ram) => self.allocated_ram = ram,
-            Message::JavaPathChanged(path) => self.java_path = path,
+            Message::RamChanged(ram) => {
+                self.allocated_ram = ram;
+                self.min_ram = self.min_ram.min(ram);
+            }
+            Message::MinRamChanged(ram) => self.min_ram = ram,
+            Message::JvmArgumentsChanged(arguments) => self.jvm_arguments = arguments,
+            Message::GameArgumentsChanged(arguments) => self.game_arguments = arguments,
+            Message::EnvironmentVariablesChanged(variables) => {
+                self.environment_variables = variables
+            }
+            Message::WrapperCommandChanged(command) => self.wrapper_command = command,
+            Message::FabricMetaUrlChanged(url) => self.fabric_meta_url = url,
+            Message::QuiltMetaUrlChanged(url) => self.quilt_meta_url = url,
+            Message::JavaPathChanged(path) => {
+                self.java_path = path;
+                self.java_test_result = None;
+            }
+            Message::JavaInstallationsLoaded(installations) => {
+                self.java_installations = installations;
+                return Task::none();
+            }
+            Message::JavaInstallationSelected(installation) => {
+                self.java_path = installation.path.to_string_lossy().into_owned();
+                self.java_test_result = Some(Ok(installation));
+            }
+            Message::TestJava => {
+                return Task::perform(
+                    JavaService::test(PathBuf::from(&self.java_path)),
+                    Message::JavaTested,
+                );
+            }
+            Message::JavaTested(result) => {
+                self.java_test_result = Some(result);
+                return Task::none();
+            }
             Message::LanguageChanged(lang) => self.selected_language = lang,
             Message::ThemeChanged(theme) => self.current_theme = theme,
+            Message::VerifyFileHashesToggled(enabled) => self.verify_file_hashes = enabled,
             Message::MinecraftRootDirChanged(minecraft_root_dir) => {
                 self.minecraft_root_dir = minecraft_root_dir
             }
             Message::BrowseMinecraftDir => {
                 let message = Task::perform(
                     async {
-                        let path = rfd::AsyncFileDialog::new()
+                        rfd::AsyncFileDialog::new()
                             .set_title("Select Minecraft Root Directory")
                             .pick_folder()
-                            .await;
-
-                        path
+                            .await
                     },
                     |folder_handle| {
                         if let Some(handle) = folder_handle {
@@ -78,13 +151,21 @@ impl SettingsScreen {
 
         let config = AppConfig {
             allocated_ram: self.allocated_ram,
+            min_ram: self.min_ram,
             java_path: self.java_path.clone(),
             language: self.selected_language.clone(),
             theme: format!("{:?}", self.current_theme),
             minecraft_root_dir: self.minecraft_root_dir.clone(),
+            verify_file_hashes: self.verify_file_hashes,
+            jvm_arguments: self.jvm_arguments.clone(),
+            game_arguments: self.game_arguments.clone(),
+            environment_variables: self.environment_variables.clone(),
+            wrapper_command: self.wrapper_command.clone(),
+            fabric_meta_url: self.fabric_meta_url.clone(),
+            quilt_meta_url: self.quilt_meta_url.clone(),
         };
 
-        let _ = ConfigRepository::save(config);
+        ConfigRepository::save(config);
 
         Task::none()
     }
@@ -109,6 +190,9 @@ impl SettingsScreen {
             self.view_section_title("GAMEPLAY"),
             self.view_settings_card(self.view_game_settings_content()),
             Space::new().height(20), // Spacer
+            self.view_section_title("ADVANCED"),
+            self.view_settings_card(self.view_advanced_settings_content()),
+            Space::new().height(20), // Spacer
             self.view_section_title("APPEARANCE & SYSTEM"),
             self.view_settings_card(self.view_launcher_settings_content()),
             Space::new().height(Length::Fill),
@@ -211,6 +295,14 @@ impl SettingsScreen {
             .align_y(Alignment::Center),
             Space::new().height(15),
             slider(1024..=16384, self.allocated_ram, Message::RamChanged),
+            Space::new().height(20),
+            self.view_setting_row(
+                "Initial Memory",
+                "Heap size allocated at startup, 0 lets Java decide",
+                text(format!("{} MB", self.min_ram)).size(14)
+            ),
+            Space::new().height(15),
+            slider(0..=self.allocated_ram, self.min_ram, Message::MinRamChanged).step(256u32),
             Space::new().height(25),
             container(Space::new())
                 .width(Length::Fill)
@@ -229,7 +321,7 @@ impl SettingsScreen {
             }),
             Space::new().height(10),
             row![
-                text_input("Select Java executable path...", &self.java_path)
+                text_input("Automatic (matches game version)", &self.java_path)
                     .on_input(Message::JavaPathChanged)
                     .padding(12),
                 Space::new().width(10),
@@ -237,6 +329,25 @@ impl SettingsScreen {
                     .padding([12, 20])
                     .style(button::primary),
             ],
+            Space::new().height(10),
+            row![
+                pick_list(
+                    self.java_installations.as_slice(),
+                    self.java_installations
+                        .iter()
+                        .find(|java| java.path.to_string_lossy() == self.java_path),
+                    Message::JavaInstallationSelected
+                )
+                .placeholder("Detected Java installations")
+                .width(Length::Fill),
+                Space::new().width(10),
+                button(text("Test").size(14))
+                    .padding([8, 20])
+                    .style(button::secondary)
+                    .on_press_maybe((!self.java_path.is_empty()).then_some(Message::TestJava)),
+            ]
+            .align_y(Alignment::Center),
+            self.view_java_test_result(),
             Space::new().height(25),
             // Minecraft directory
             text("Minecraft Root Directory").size(16).font(Font {
@@ -261,6 +372,99 @@ impl SettingsScreen {
         .into()
     }
 
+    /// Result of the last Java test
+    fn view_java_test_result(&self) -> Element<'_, Message> {
+        let Some(result) = &self.java_test_result else {
+            return Space::new().into();
+        };
+
+        let (label, is_ok) = match result {
+            Ok(java) => (
+                format!(
+                    "Java {} ({}) works",
+                    java.version,
+                    java.vendor.as_deref().unwrap_or("unknown vendor")
+                ),
+                true,
+            ),
+            Err(e) => (e.clone(), false),
+        };
+
+        container(text(label).size(12).style(move |theme: &Theme| {
+            let palette = theme.extended_palette();
+            text::Style {
+                color: Some(if is_ok {
+                    palette.success.base.color
+                } else {
+                    palette.danger.base.color
+                }),
+            }
+        }))
+        .padding([8, 0])
+        .into()
+    }
+
+    /// Advanced launch options content
+    fn view_advanced_settings_content(&self) -> Element<'_, Message> {
+        let field = |title, placeholder, value, on_input: fn(String) -> Message| {
+            column![
+                text(title).size(16).font(Font {
+                    weight: iced::font::Weight::Semibold,
+                    ..Default::default()
+                }),
+                Space::new().height(10),
+                text_input(placeholder, value)
+                    .on_input(on_input)
+                    .padding(12),
+            ]
+        };
+
+        column![
+            field(
+                "JVM Arguments",
+                "-XX:+UseG1GC -Dfile.encoding=UTF-8",
+                &self.jvm_arguments,
+                Message::JvmArgumentsChanged
+            ),
+            Space::new().height(20),
+            field(
+                "Game Arguments",
+                "--width 1280 --height 720",
+                &self.game_arguments,
+                Message::GameArgumentsChanged
+            ),
+            Space::new().height(20),
+            field(
+                "Environment Variables",
+                "KEY=value OTHER=\"value with spaces\"",
+                &self.environment_variables,
+                Message::EnvironmentVariablesChanged
+            ),
+            Space::new().height(20),
+            field(
+                "Wrapper Command",
+                "gamemoderun, mangohud, prime-run...",
+                &self.wrapper_command,
+                Message::WrapperCommandChanged
+            ),
+            Space::new().height(20),
+            field(
+                "Fabric Meta URL",
+                DEFAULT_FABRIC_META_URL,
+                &self.fabric_meta_url,
+                Message::FabricMetaUrlChanged
+            ),
+            Space::new().height(20),
+            field(
+                "Quilt Meta URL",
+                DEFAULT_QUILT_META_URL,
+                &self.quilt_meta_url,
+                Message::QuiltMetaUrlChanged
+            ),
+        ]
+        .into()
+    }
+
     /// Launcher settings content
     fn view_launcher_settings_content(&self) -> Element<'_, Message> {
         let languages = vec!["English".to_string(), "Vietnamese".to_string()];
@@ -289,6 +493,13 @@ impl SettingsScreen {
                 )
                 .width(Length::Fixed(160.0))
             ),
+            Space::new().height(20),
+            // Verify files
+            self.view_setting_row(
+                "Verify Game Files",
+                "Check SHA-1 of every installed file before launch (slower)",
+                toggler(self.verify_file_hashes).on_toggle(Message::VerifyFileHashesToggled)
+            ),
         ]
         .into()
     }